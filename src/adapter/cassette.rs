@@ -0,0 +1,329 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+use super::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+
+/// 저장하지 않는 query 파라미터
+const SCRUBBED_PARAMS: [&str; 1] = ["signature"];
+/// 요청마다 바뀌므로 replay할 때 비교하지 않는 query 파라미터
+const VOLATILE_PARAMS: [&str; 3] = ["signature", "timestamp", "newClientOrderId"];
+
+/// 요청/응답 쌍을 저장한 파일
+/// api key header와 `signature`는 저장하지 않는다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl Cassette {
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("fail to read {}: {}", path.display(), e)))?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let text = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, text)
+            .map_err(|e| Error::Config(format!("fail to write {}: {}", path.display(), e)))
+    }
+}
+
+impl RecordedRequest {
+    fn new(request: &HttpRequest) -> Self {
+        RecordedRequest {
+            method: request.method.to_string(),
+            path: request.url.path().to_string(),
+            query: request.url.query().map(|q| remove_params(q, &SCRUBBED_PARAMS)).unwrap_or_default(),
+            body: request.body.as_deref().map(|b| remove_params(b, &SCRUBBED_PARAMS)),
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method
+            && self.path == other.path
+            && remove_params(&self.query, &VOLATILE_PARAMS) == remove_params(&other.query, &VOLATILE_PARAMS)
+            && self.body.as_deref().map(|b| remove_params(b, &VOLATILE_PARAMS))
+                == other.body.as_deref().map(|b| remove_params(b, &VOLATILE_PARAMS))
+    }
+}
+
+impl RecordedResponse {
+    fn new(response: &HttpResponse) -> Self {
+        let headers = response.headers
+            .iter()
+            .filter_map(|(k, v)| Some((k.as_str().to_string(), v.to_str().ok()?.to_string())))
+            .collect();
+
+        RecordedResponse { status: response.status, headers, body: response.body.clone() }
+    }
+
+    fn to_response(&self) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        for (k, v) in &self.headers {
+            if let (Ok(k), Ok(v)) = (HeaderName::from_bytes(k.as_bytes()), HeaderValue::from_str(v)) {
+                headers.insert(k, v);
+            }
+        }
+
+        HttpResponse { status: self.status, headers, body: self.body.clone() }
+    }
+}
+
+enum Mode<R> {
+    Record { inner: R, path: PathBuf },
+    Replay,
+}
+
+struct CassetteState {
+    cassette: Cassette,
+    used: Vec<bool>,
+}
+
+/// 요청/응답을 cassette 파일에 기록하거나, 기록된 응답을 다시 돌려주는 transport
+/// - `record`: 실제 transport로 요청을 보내고 요청마다 파일에 저장한다.
+/// - `replay`: 네트워크 없이 method, path, query가 같은 기록을 순서대로 돌려준다.
+///   한 번 사용한 기록은 다시 사용하지 않는다.
+/// # Example
+/// ```no_run
+/// use crypto_trading::adapter::{cassette::CassetteTransport, client::BinanceClient, common::BinanceCommon, transport::ReqwestTransport};
+///
+/// // 녹화
+/// let transport = CassetteTransport::record("klines.json", ReqwestTransport::default());
+/// let common = BinanceCommon::with_client(BinanceClient::builder().build_with_transport(transport).unwrap());
+///
+/// // 재생
+/// let transport = CassetteTransport::replay("klines.json").unwrap();
+/// let common = BinanceCommon::with_client(BinanceClient::builder().build_with_transport(transport).unwrap());
+/// ```
+pub struct CassetteTransport<R: Transport = ReqwestTransport> {
+    mode: Arc<Mode<R>>,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl<R: Transport> Clone for CassetteTransport<R> {
+    fn clone(&self) -> Self {
+        CassetteTransport { mode: self.mode.clone(), state: self.state.clone() }
+    }
+}
+
+impl CassetteTransport {
+    pub fn replay(path: impl AsRef<Path>) -> crate::Result<Self> {
+        Ok(CassetteTransport::from_cassette(Cassette::load(path)?))
+    }
+
+    pub fn from_cassette(cassette: Cassette) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        CassetteTransport {
+            mode: Arc::new(Mode::Replay),
+            state: Arc::new(Mutex::new(CassetteState { cassette, used })),
+        }
+    }
+}
+
+impl<R: Transport> CassetteTransport<R> {
+    /// `path`의 기존 내용은 덮어쓴다.
+    pub fn record(path: impl Into<PathBuf>, inner: R) -> Self {
+        CassetteTransport {
+            mode: Arc::new(Mode::Record { inner, path: path.into() }),
+            state: Arc::new(Mutex::new(CassetteState { cassette: Cassette::default(), used: Vec::new() })),
+        }
+    }
+
+    /// 지금까지 기록된(또는 불러온) 내용
+    pub fn cassette(&self) -> Cassette {
+        self.state.lock().unwrap().cassette.clone()
+    }
+}
+
+impl<R: Transport> Transport for CassetteTransport<R> {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+        let recorded = RecordedRequest::new(&request);
+
+        match self.mode.as_ref() {
+            Mode::Record { inner, path } => {
+                let response = inner.send(request).await?;
+
+                let cassette = {
+                    let mut state = self.state.lock().unwrap();
+                    state.cassette.interactions.push(Interaction {
+                        request: recorded,
+                        response: RecordedResponse::new(&response),
+                    });
+                    state.cassette.clone()
+                };
+                cassette.save(path)?;
+
+                Ok(response)
+            },
+            Mode::Replay => {
+                let mut state = self.state.lock().unwrap();
+                let CassetteState { cassette, used } = &mut *state;
+
+                let idx = cassette.interactions
+                    .iter()
+                    .enumerate()
+                    .position(|(i, interaction)| !used[i] && interaction.request.matches(&recorded))
+                    .ok_or_else(|| Error::Config(format!("no recorded response for {} {}", recorded.method, recorded.path)))?;
+
+                used[idx] = true;
+                Ok(cassette.interactions[idx].response.to_response())
+            },
+        }
+    }
+}
+
+/// `a=1&b=2` 형태의 문자열에서 `params`를 뺀다.
+fn remove_params(query: &str, params: &[&str]) -> String {
+    query
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split('=').next().unwrap_or_default();
+            !pair.is_empty() && !params.contains(&key)
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapter::{client::BinanceClient, common::BinanceCommon, mock::{MockRoute, MockTransport}, trade::BinanceTrade},
+        model::binance_model::KlineInterval,
+        port::binance_port::{CommonPort, TradePort},
+    };
+
+    use super::*;
+
+    const KLINES: &str = r#"[[1700000000000,"1.0","2.0","0.5","1.5","100.0",1700000059999,"150.0",10,"60.0","90.0","0"]]"#;
+    const ORDER: &str = r#"{"orderId":1,"symbol":"BTCUSDT","status":"NEW","clientOrderId":"ct-1"}"#;
+
+    fn interaction(method: &str, path: &str, query: &str, body: Option<&str>, response: &str) -> Interaction {
+        Interaction {
+            request: RecordedRequest {
+                method: method.to_string(),
+                path: path.to_string(),
+                query: query.to_string(),
+                body: body.map(str::to_string),
+            },
+            response: RecordedResponse { status: 200, headers: BTreeMap::new(), body: response.to_string() },
+        }
+    }
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    async fn place_order<R: Transport>(client: BinanceClient<R>) -> crate::Result<String> {
+        BinanceTrade::with_client(client)
+            .new_order(some("BTCUSDT"), some("BUY"), some("LIMIT"), some("GTC"), some("0.001"), some("30000"), None, None)
+            .await
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_responses() {
+        let cassette = Cassette {
+            interactions: vec![
+                interaction("GET", "/fapi/v1/klines", "symbol=BTCUSDT&interval=1m&limit=1", None, KLINES),
+                // 녹화할 때의 timestamp, newClientOrderId는 replay할 때와 다르다.
+                interaction(
+                    "POST",
+                    "/fapi/v1/order",
+                    "",
+                    Some("symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=GTC&quantity=0.001&price=30000&newClientOrderId=ct-0-0&timestamp=1"),
+                    ORDER,
+                ),
+            ],
+        };
+        let transport = CassetteTransport::from_cassette(cassette);
+        let client = BinanceClient::builder()
+            .credentials("api_key", "secret_key")
+            .build_with_transport(transport)
+            .unwrap();
+
+        let klines = BinanceCommon::with_client(client.clone())
+            .get_kline("BTCUSDT".to_string(), KlineInterval::Min1, Some(1))
+            .await
+            .unwrap();
+        assert_eq!(klines.kline_list.len(), 1);
+        assert_eq!(klines.kline_list[0].trade_count, 10);
+
+        assert_eq!(place_order(client.clone()).await.unwrap(), ORDER);
+
+        // 한 번 사용한 기록은 다시 사용하지 않는다.
+        assert!(matches!(place_order(client).await, Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn does_not_replay_different_request() {
+        let cassette = Cassette {
+            interactions: vec![interaction("GET", "/fapi/v1/klines", "symbol=ETHUSDT&interval=1m&limit=1", None, KLINES)],
+        };
+        let client = BinanceClient::builder().build_with_transport(CassetteTransport::from_cassette(cassette)).unwrap();
+
+        let result = BinanceCommon::with_client(client)
+            .get_kline("BTCUSDT".to_string(), KlineInterval::Min1, Some(1))
+            .await;
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn records_without_secrets() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::post("/fapi/v1/order").respond(200, ORDER));
+
+        let path = std::env::temp_dir().join(format!("cassette-{}.json", std::process::id()));
+        let transport = CassetteTransport::record(path.clone(), mock);
+        let client = BinanceClient::builder()
+            .credentials("api_key", "secret_key")
+            .build_with_transport(transport.clone())
+            .unwrap();
+        place_order(client).await.unwrap();
+
+        let cassette = Cassette::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let text = serde_json::to_string(&cassette).unwrap();
+        assert!(!text.contains("signature"));
+        assert!(!text.contains("api_key"));
+        assert_eq!(cassette.interactions.len(), 1);
+        assert!(cassette.interactions[0].request.body.as_deref().unwrap().contains("newClientOrderId="));
+
+        // 다른 timestamp, newClientOrderId로도 replay된다.
+        let client = BinanceClient::builder()
+            .credentials("api_key", "secret_key")
+            .build_with_transport(CassetteTransport::from_cassette(cassette))
+            .unwrap();
+        assert_eq!(place_order(client).await.unwrap(), ORDER);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::{model::binance_model::{BaseUrl, BinanceRequest, Endpoint, WsBaseUrl}, Error};
+
+use super::{adapter_utils, clock::ServerClock, credentials::Credentials, rate_limit::RateLimiter, retry::RetryPolicy, symbol_rules::SymbolRulesCache, transport::{ReqwestTransport, Transport}};
+
+/// 모든 adapter가 공유하는 client
+/// 내부의 `reqwest::Client`가 connection pool을 가지고 있기 때문에 clone해서 같이 쓰면 된다.
+/// 테스트에서는 `build_with_transport`로 `MockTransport` 등을 사용할 수 있다.
+/// # Example
+/// ```no_run
+/// use crypto_trading::adapter::{client::BinanceClient, common::BinanceCommon, trade::BinanceTrade};
+///
+/// let client = BinanceClient::builder()
+///     .credentials("api_key", "secret_key")
+///     .build()
+///     .unwrap();
+///
+/// let common = BinanceCommon::with_client(client.clone());
+/// let trade = BinanceTrade::with_client(client);
+/// ```
+pub struct BinanceClient<R: Transport = ReqwestTransport> {
+    inner: Arc<ClientInner<R>>,
+}
+
+impl<R: Transport> Clone for BinanceClient<R> {
+    fn clone(&self) -> Self {
+        BinanceClient { inner: self.inner.clone() }
+    }
+}
+
+struct ClientInner<R> {
+    transport: R,
+    credentials: Option<Credentials>,
+    base_url: BaseUrl,
+    ws_base_url: WsBaseUrl,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    clock: ServerClock,
+    symbol_rules: SymbolRulesCache,
+    recv_window: Option<u64>,
+}
+
+impl BinanceClient {
+    pub fn builder() -> BinanceClientBuilder {
+        BinanceClientBuilder::new()
+    }
+
+    /// 환경변수의 key로 기본 설정 client를 만든다.
+    /// 환경변수가 없으면 `Error::Config`를 반환한다.
+    pub fn from_env() -> crate::Result<Self> {
+        BinanceClient::builder()
+            .with_credentials(Credentials::from_env()?)
+            .build()
+    }
+
+    pub fn http(&self) -> &reqwest::Client {
+        self.inner.transport.client()
+    }
+}
+
+impl<R: Transport> BinanceClient<R> {
+    pub fn transport(&self) -> &R {
+        &self.inner.transport
+    }
+
+    pub fn base_url(&self) -> BaseUrl {
+        self.inner.base_url.clone()
+    }
+
+    pub fn ws_base_url(&self) -> WsBaseUrl {
+        self.inner.ws_base_url.clone()
+    }
+
+    /// client를 공유하는 모든 adapter가 같은 제한기를 사용한다.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.inner.rate_limiter
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.inner.retry_policy
+    }
+
+    pub fn clock(&self) -> &ServerClock {
+        &self.inner.clock
+    }
+
+    /// 주문 전에 가격, 수량을 맞추는 데 사용하는 symbol filter
+    pub fn symbol_rules(&self) -> &SymbolRulesCache {
+        &self.inner.symbol_rules
+    }
+
+    /// 서버 시간 offset이 적용된 timestamp (ms)
+    pub fn timestamp(&self) -> u64 {
+        self.inner.clock.timestamp()
+    }
+
+    /// fapi/v1/time으로 서버 시간을 받아와 offset을 다시 계산한다.
+    /// 계산된 offset(ms)을 반환한다.
+    pub async fn sync_time(&self) -> crate::Result<i64> {
+        adapter_utils::sync_server_time(self).await
+    }
+
+    /// `interval`마다 서버 시간을 다시 맞추는 task를 실행한다.
+    /// 실패하면 이전 offset을 그대로 사용하고 다음 주기에 다시 시도한다.
+    /// tokio runtime 안에서 호출해야 한다.
+    pub fn spawn_time_sync(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let _ = client.sync_time().await;
+            }
+        })
+    }
+
+    /// signed 요청에 기본으로 붙는 recvWindow (ms)
+    pub fn recv_window(&self) -> Option<u64> {
+        self.inner.recv_window
+    }
+
+    pub fn credentials(&self) -> crate::Result<&Credentials> {
+        self.inner.credentials.as_ref().ok_or_else(|| Error::Config("credentials are not set".to_string()))
+    }
+
+    /// endpoint가 선언한 method, security type, weight에 맞게 요청하고 응답 body를 반환한다.
+    /// `UserData`, `Trade` endpoint는 서명하고, `MarketData`는 api key header만 붙인다.
+    pub async fn execute<T>(&self, endpoint: T) -> crate::Result<String>
+    where
+        T: Endpoint + Into<String>,
+    {
+        adapter_utils::execute(self, BinanceRequest::new(self.base_url(), endpoint)).await
+    }
+
+    /// 요청별 recvWindow 등을 설정한 `BinanceRequest`를 보낸다.
+    pub async fn execute_request<T>(&self, request: BinanceRequest<T>) -> crate::Result<String>
+    where
+        T: Endpoint + Into<String>,
+    {
+        adapter_utils::execute(self, request).await
+    }
+}
+
+impl Default for BinanceClient {
+    /// key 없이 public api만 사용하는 client
+    fn default() -> Self {
+        BinanceClient::builder()
+            .build()
+            .expect("fail to build default client")
+    }
+}
+
+pub struct BinanceClientBuilder {
+    credentials: Option<crate::Result<Credentials>>,
+    base_url: BaseUrl,
+    ws_base_url: Option<WsBaseUrl>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    default_headers: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    recv_window: Option<u64>,
+}
+
+impl BinanceClientBuilder {
+    pub fn new() -> Self {
+        BinanceClientBuilder {
+            credentials: None,
+            base_url: BaseUrl::Future,
+            ws_base_url: None,
+            timeout: Some(Duration::from_secs(10)),
+            connect_timeout: None,
+            proxy: None,
+            default_headers: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            recv_window: None,
+        }
+    }
+
+    /// key가 비어있으면 `build`에서 `Error::Config`를 반환한다.
+    pub fn credentials(mut self, api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        self.credentials = Some(Credentials::new(api_key, secret_key));
+        self
+    }
+
+    /// 환경변수, 설정 파일, keystore 등에서 읽어온 `Credentials`를 사용한다.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(Ok(credentials));
+        self
+    }
+
+    /// 모든 adapter가 이 주소로 요청한다. (기본값: `BaseUrl::Future`)
+    pub fn base_url(mut self, base_url: BaseUrl) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// 설정하지 않으면 `base_url`에 맞는 주소를 사용한다.
+    pub fn ws_base_url(mut self, ws_base_url: WsBaseUrl) -> Self {
+        self.ws_base_url = Some(ws_base_url);
+        self
+    }
+
+    /// 요청 전체에 대한 timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 모든 요청에 사용할 proxy (예: `http://127.0.0.1:8080`)
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 재시도 정책 (기본값: `RetryPolicy::default()`)
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// signed 요청의 기본 recvWindow (ms, 최대 60000)
+    /// 설정하지 않으면 바이낸스 기본값(5000)이 사용된다.
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn build(self) -> crate::Result<BinanceClient> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.default_headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| Error::Config(format!("invalid header name: {}", name)))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| Error::Config(format!("invalid header value for {}", name)))?;
+            headers.insert(name, value);
+        }
+
+        let mut builder = reqwest::Client::builder().default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        let http = builder.build()?;
+
+        self.build_with_transport(ReqwestTransport::new(http))
+    }
+
+    /// reqwest 대신 `transport`로 요청을 보내는 client를 만든다.
+    /// timeout, proxy, default header 설정은 사용되지 않는다.
+    pub fn build_with_transport<R: Transport>(self, transport: R) -> crate::Result<BinanceClient<R>> {
+        if let Some(recv_window) = self.recv_window {
+            check_recv_window(recv_window)?;
+        }
+        let credentials = self.credentials.transpose()?;
+        url::Url::parse(&self.base_url.url())?;
+        let ws_base_url = self.ws_base_url.unwrap_or_else(|| self.base_url.ws());
+
+        Ok(BinanceClient {
+            inner: Arc::new(ClientInner {
+                transport,
+                credentials,
+                base_url: self.base_url,
+                ws_base_url,
+                rate_limiter: RateLimiter::new(),
+                retry_policy: self.retry_policy,
+                clock: ServerClock::new(),
+                symbol_rules: SymbolRulesCache::new(),
+                recv_window: self.recv_window,
+            }),
+        })
+    }
+}
+
+impl Default for BinanceClientBuilder {
+    fn default() -> Self {
+        BinanceClientBuilder::new()
+    }
+}
+
+/// 바이낸스는 60000ms보다 큰 recvWindow를 허용하지 않는다.
+pub(crate) fn check_recv_window(recv_window: u64) -> crate::Result<()> {
+    if recv_window == 0 || recv_window > 60_000 {
+        return Err(Error::Config(format!("recvWindow must be between 1 and 60000: {}", recv_window)));
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::{
+    sync::atomic::{AtomicI64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// 서버 시간과 로컬 시간의 차이(ms)를 보관한다.
+/// signed 요청의 `timestamp`는 로컬 시간에 이 offset을 더한 값을 사용한다.
+pub struct ServerClock {
+    offset_ms: AtomicI64,
+}
+
+impl ServerClock {
+    pub fn new() -> Self {
+        ServerClock { offset_ms: AtomicI64::new(0) }
+    }
+
+    /// 서버 시간 - 로컬 시간 (ms)
+    pub fn offset(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offset(&self, offset_ms: i64) {
+        self.offset_ms.store(offset_ms, Ordering::Relaxed);
+    }
+
+    /// offset이 적용된 현재 timestamp (ms)
+    pub fn timestamp(&self) -> u64 {
+        (local_timestamp() + self.offset()).max(0) as u64
+    }
+}
+
+impl Default for ServerClock {
+    fn default() -> Self {
+        ServerClock::new()
+    }
+}
+
+pub(crate) fn local_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
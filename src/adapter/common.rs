@@ -1,89 +1,184 @@
-use anyhow::{Context, Ok};
+use std::collections::HashSet;
+
 use serde_json::{from_value, Value};
-use crate::{model::binance_model::{BinanceRequest, BinanceSymbol, Endpoint, Kline, Klines, Ticker, Tickers}, port::binance_port::CommonPort};
-use crate::model::binance_model::{BaseUrl, CommonEndpoint};
+use crate::{model::binance_model::{
+    AggTrade, ContractType, ExchangeInfo, FundingInfo, FundingRate, Kline, KlineInterval, Klines, LongShortRatio, LongShortRatioKind, OpenInterest,
+    OpenInterestHist, OrderBook, PremiumIndex, StatisticsPeriod, SymbolQuery, SymbolStatus, TakerBuySellVolume, Ticker, Tickers, Trade,
+}, port::binance_port::CommonPort};
+use crate::{model::binance_model::CommonEndpoint, Error};
 
-use super::adapter_utils;
+use super::{client::BinanceClient, pager::{AggTradePager, KlinePager}, transport::{ReqwestTransport, Transport}};
 
-pub struct BinanceCommon {
+pub struct BinanceCommon<R: Transport = ReqwestTransport> {
+    client: BinanceClient<R>,
 }
 
 impl BinanceCommon {
+    /// key가 필요 없는 기본 client로 생성한다.
     pub fn new() -> Self{
         BinanceCommon {
+            client: BinanceClient::default(),
         }
     }
-} 
 
-impl BinanceCommon {
-    fn parse_binance_kline(&self, symbol: String, interval: String, value: Value, idx: u64) -> anyhow::Result<Kline>{
+}
+
+impl<R: Transport> BinanceCommon<R> {
+    /// 다른 adapter와 `BinanceClient`를 공유할 때 사용한다.
+    pub fn with_client(client: BinanceClient<R>) -> Self {
+        BinanceCommon { client }
+    }
+
+    pub fn client(&self) -> &BinanceClient<R> {
+        &self.client
+    }
+
+    /// fapi/v1/exchangeInfo의 `rateLimits`로 client의 rate limiter를 설정한다.
+    pub async fn sync_rate_limits(&self) -> crate::Result<()> {
+        let exchange_info = self.get_exchange_info().await?;
+
+        self.client.rate_limiter().configure(&exchange_info.rate_limits);
+
+        Ok(())
+    }
+
+    /// fapi/v1/exchangeInfo의 symbol filter로 client의 `SymbolRulesCache`를 채운다.
+    /// 이후 `new_order`는 가격, 수량을 tick size, step size에 맞추고 filter를 먼저 검사한다.
+    pub async fn sync_symbol_rules(&self) -> crate::Result<()> {
+        let exchange_info = self.get_exchange_info().await?;
+
+        self.client.rate_limiter().configure(&exchange_info.rate_limits);
+        self.client.symbol_rules().update(&exchange_info);
+
+        Ok(())
+    }
+}
+
+impl<R: Transport> BinanceCommon<R> {
+    /// `start_time`부터 `end_time`까지(ms, 포함)의 aggTrade를 page 단위로 가져온다.
+    pub fn agg_trades_pager(&self, symbol: &str, start_time: u64, end_time: u64) -> AggTradePager<'_, R> {
+        AggTradePager::new(self, symbol, start_time, end_time)
+    }
+
+    /// `start_time`부터 `end_time`까지(ms, 포함) open된 kline을 page 단위로 가져온다.
+    /// page가 도착할 때마다 처리하고 싶을 때 사용한다.
+    pub fn klines_pager(&self, symbol: &str, interval: KlineInterval, start_time: u64, end_time: u64) -> KlinePager<'_, R> {
+        KlinePager::new(self, symbol, interval, start_time, end_time)
+    }
+
+    /// `start_time`부터 한 page의 kline
+    pub(crate) async fn get_kline_page(&self, symbol: &str, interval: KlineInterval, start_time: u64, end_time: u64, limit: i32) -> crate::Result<Vec<Kline>> {
+        let common_endpoint = CommonEndpoint::Klines {
+            symbol: symbol.to_string(),
+            interval,
+            start_time: Some(start_time),
+            end_time: Some(end_time),
+            limit: Some(limit),
+        };
+        let res = self.client.execute(common_endpoint).await?;
+
+        self.parse_binance_klines(symbol, interval, &res)
+    }
+}
+
+/// 거래 중인 USDT 무기한 선물
+fn usdt_perpetuals() -> SymbolQuery {
+    SymbolQuery::new()
+        .quote_asset("USDT")
+        .status(SymbolStatus::Trading)
+        .contract_type(ContractType::Perpetual)
+}
+
+impl Default for BinanceCommon {
+    fn default() -> Self {
+        BinanceCommon::new()
+    }
+}
+
+impl<R: Transport> BinanceCommon<R> {
+    /// 잘못된 값이 있으면 몇 번째 kline의 어떤 필드인지 담은 `Error::Decode`를 반환한다.
+    /// 0으로 채운 가짜 kline을 만들지 않는다.
+    fn parse_binance_kline(&self, symbol: String, interval: KlineInterval, value: Value, idx: u64) -> crate::Result<Kline>{
         // 여기서 받은 value 값은 하나의 값이라고 알면 됨. 
-        let arr = value.as_array().ok_or_else(|| anyhow::anyhow!("Expected array"))?;
-        if arr.len() < 7 {
-            return Err(anyhow::anyhow!("Array too short for Kline"));
+        let arr = value
+            .as_array()
+            .ok_or_else(|| Error::Decode(format!("kline[{}]: expected array, got {}", idx, value)))?;
+        if arr.len() < 11 {
+            return Err(Error::Decode(format!("kline[{}]: expected at least 11 fields, got {}", idx, arr.len())));
         }
 
+        let price = |i: usize, name: &str| -> crate::Result<f64> {
+            arr[i]
+                .as_str()
+                .and_then(|v| v.parse::<f64>().ok())
+                .ok_or_else(|| Error::Decode(format!("kline[{}].{}: invalid number {}", idx, name, arr[i])))
+        };
+        let integer = |i: usize, name: &str| -> crate::Result<u64> {
+            arr[i]
+                .as_u64()
+                .ok_or_else(|| Error::Decode(format!("kline[{}].{}: invalid integer {}", idx, name, arr[i])))
+        };
+
         Ok(Kline {
             symbol,
             interval, 
-            open_time: arr[0].as_u64().unwrap_or(0), 
-            open: arr[1].as_str().unwrap_or("0.0").parse::<f64>().unwrap_or(0.0), 
-            high: arr[2].as_str().unwrap_or("0.0").parse::<f64>().unwrap_or(0.0), 
-            low: arr[3].as_str().unwrap_or("0.0").parse::<f64>().unwrap_or(0.0), 
-            close: arr[4].as_str().unwrap_or("0.0").parse::<f64>().unwrap_or(0.0), 
-            volume: arr[5].as_str().unwrap_or("0.0").parse::<f64>().unwrap_or(0.0), 
-            close_time: arr[6].as_u64().unwrap_or(0),  
-            idx: idx,
+            open_time: integer(0, "open_time")?, 
+            open: price(1, "open")?, 
+            high: price(2, "high")?, 
+            low: price(3, "low")?, 
+            close: price(4, "close")?, 
+            volume: price(5, "volume")?, 
+            close_time: integer(6, "close_time")?,  
+            quote_volume: price(7, "quote_volume")?, 
+            trade_count: integer(8, "trade_count")?, 
+            taker_buy_base_volume: price(9, "taker_buy_base_volume")?, 
+            taker_buy_quote_volume: price(10, "taker_buy_quote_volume")?, 
+            idx,
         })
     }
+
+    /// klines 응답 body를 파싱한다.
+    fn parse_binance_klines(&self, symbol: &str, interval: KlineInterval, body: &str) -> crate::Result<Vec<Kline>> {
+        let parsed: Value = serde_json::from_str(body)?;
+        // pasred -> array -> array
+        parsed
+            .as_array()
+            .ok_or_else(|| Error::Decode(format!("klines: expected array, got {}", parsed)))?
+            .iter()
+            .enumerate()
+            .map(|(i, c)| self.parse_binance_kline(symbol.to_string(), interval, c.clone(), i as u64))
+            .collect()
+    }
 }
 
-impl CommonPort for BinanceCommon{
+impl<R: Transport> CommonPort for BinanceCommon<R>{
 
     /// `symbol`, `interval`, `limit`을 설정하여 kline에 대한 정보를 가져와 `Klines` 구조체로 파싱하여 반환하는
-    async fn get_kline(&self, symbol: String, interval: &str, limit: Option<i32>) -> anyhow::Result<Klines> {
-        let common_endpoint = CommonEndpoint::Klines { symbol: symbol.to_string(), interval: interval.to_string(), limit: limit };
-        let binance_request = BinanceRequest::new(BaseUrl::future, common_endpoint);
-        
-        let res = adapter_utils::request("get", binance_request).await?;
+    async fn get_kline(&self, symbol: String, interval: KlineInterval, limit: Option<i32>) -> crate::Result<Klines> {
+        let common_endpoint = CommonEndpoint::Klines { symbol: symbol.to_string(), interval, start_time: None, end_time: None, limit };
+        let res = self.client.execute(common_endpoint).await?;
 
-        let parsed: Value = serde_json::from_str(res.as_str()).context("fail to parse json")?;               
-        // pasred -> array -> array
-        let klines: Vec<Kline> = parsed
-                        .as_array()
-                        .expect("error:")
-                        .iter()
-                        .enumerate()
-                        .map(|(i, c)| {
-                            let kline = self.parse_binance_kline(symbol.clone(), interval.to_string(), c.clone(), i as u64).unwrap_or(Kline::default()); 
-                            kline
-                        })
-                        .collect();
+        let klines = self.parse_binance_klines(&symbol, interval, &res)?;
         
         Ok(Klines::new(klines))
     }
     
 
-    /// fapi/v1/exchangeInfo 
-    /// `exchangeInfo`를 파싱하여 USDT로 거래되는 symbol을 list형태로 얻으려는 함수 
-    /// Vec<String> 형태로 반환한다.
-    /// symbol의 마지막 4글자가 `USDT인 경우만 필터링한다.
-    async fn get_symbol_list(&self) -> anyhow::Result<Vec<String>> {
+    /// fapi/v1/exchangeInfo
+    async fn get_exchange_info(&self) -> crate::Result<ExchangeInfo> {
         let common_endpoint = CommonEndpoint::ExchnageInfo;
-        let binance_request = BinanceRequest::new(BaseUrl::future, common_endpoint);
+        let res = self.client.execute(common_endpoint).await?;
 
-        let res = adapter_utils::request("get", binance_request).await?;
-
-        let v: Value = serde_json::from_str(res.as_str()).context("fail to parse jsono")?;
-        
-        let symboles_value = &v["symbols"];
+        Ok(serde_json::from_str(res.as_str())?)
+    }
 
-        let symbols: Vec<BinanceSymbol> = from_value(symboles_value.clone()).context("symbols parsing fail")?;
+    /// fapi/v1/exchangeInfo 
+    /// 거래 중인 USDT 무기한(perpetual) 선물 symbol을 list형태로 얻으려는 함수 
+    /// Vec<String> 형태로 반환한다.
+    async fn get_symbol_list(&self) -> crate::Result<Vec<String>> {
+        let exchange_info = self.get_exchange_info().await?;
 
-        let symbol_vec: Vec<String> = symbols.iter()
-            .filter(|s| {
-                s.symbol.ends_with("USDT")
-            })
+        let symbol_vec: Vec<String> = exchange_info.query(&usdt_perpetuals())
             .map(|s| {
                 s.symbol.clone()
             }).collect();
@@ -91,33 +186,518 @@ impl CommonPort for BinanceCommon{
         Ok(symbol_vec)
     }
     
-    /// fapi/v1/ticker/24hr
-    async fn get_symbol_with_volume(&self) -> anyhow::Result<Vec<Ticker>> {
-        let common_endpoint = CommonEndpoint::Ticker;
-        let binance_request = BinanceRequest::new(BaseUrl::future, common_endpoint);
+    /// fapi/v1/exchangeInfo, fapi/v1/ticker/24hr
+    /// 거래 중인 USDT 무기한 선물 symbol을 quote volume이 큰 순서로 반환한다.
+    async fn get_symbol_with_volume(&self) -> crate::Result<Vec<Ticker>> {
+        let exchange_info = self.get_exchange_info().await?;
+        let query = usdt_perpetuals();
+        let symbols: HashSet<&str> = exchange_info.query(&query).map(|s| s.symbol.as_str()).collect();
 
-        let res = adapter_utils::request("get", binance_request).await?;
+        let mut tickers = self.get_tickers().await?;
+        tickers.sort_by_quote_volume();
 
-        let mut parsed: Vec<Ticker> = serde_json::from_str(res.as_str()).context("fail to parse json")?;
-        
-        parsed.sort_by(|a, b| {
-            let a: f64 = a.quote_volume.parse().unwrap();
-            let b: f64 = b.quote_volume.parse().unwrap();
-            b.partial_cmp(&a).unwrap()
-        });
-
-        let parsed: Vec<_> = parsed.iter()
-            .filter(|t|{
-                t.symbol.ends_with("USDT")
-            })
-            .map(|t| t.clone())
+        let parsed: Vec<_> = tickers.tickers.into_iter()
+            .filter(|t| symbols.contains(t.symbol.as_str()))
             .collect();
 
-        // parsed.iter().for_each(|t| {
-        //     println!("symbol: {}, quoteVolume: {}", t.symbol, t.quote_volume);
-        // });
-
         Ok(parsed)
     }
+
+    /// fapi/v1/ticker/24hr
+    async fn get_ticker(&self, symbol: &str) -> crate::Result<Ticker> {
+        let common_endpoint = CommonEndpoint::Ticker { symbol: Some(symbol.to_string()) };
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// fapi/v1/ticker/24hr 전체 symbol
+    async fn get_tickers(&self) -> crate::Result<Tickers> {
+        let common_endpoint = CommonEndpoint::Ticker { symbol: None };
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// fapi/v1/depth
+    /// `limit`: 5, 10, 20, 50, 100, 500, 1000 (기본값 500)
+    async fn get_order_book(&self, symbol: &str, limit: Option<u32>) -> crate::Result<OrderBook> {
+        let common_endpoint = CommonEndpoint::OrderBook { symbol: symbol.to_string(), limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        let order_book: OrderBook = serde_json::from_str(res.as_str())?;
+
+        Ok(order_book)
+    }
+
+    /// fapi/v1/trades
+    async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> crate::Result<Vec<Trade>> {
+        let common_endpoint = CommonEndpoint::RecentTradesList { symbol: symbol.to_string(), limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// fapi/v1/historicalTrades
+    /// api key가 설정된 client가 필요하다.
+    async fn get_historical_trades(&self, symbol: &str, limit: Option<u32>, from_id: Option<u64>) -> crate::Result<Vec<Trade>> {
+        let common_endpoint = CommonEndpoint::HistoricalTrades { symbol: symbol.to_string(), limit, from_id };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// fapi/v1/aggTrades
+    /// 긴 기간은 `agg_trades_pager`를 사용한다.
+    async fn get_agg_trades(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> crate::Result<Vec<AggTrade>> {
+        let common_endpoint = CommonEndpoint::AggTrades { symbol: symbol.to_string(), from_id, start_time, end_time, limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// `start_time`부터 `end_time`까지(ms, 포함) open된 kline을 모두 가져와 하나의 `Klines`로 합친다.
+    /// 여러 번 나눠서 요청하며, 요청마다 client의 rate limiter를 거친다.
+    async fn get_klines_range(&self, symbol: &str, interval: KlineInterval, start_time: u64, end_time: u64) -> crate::Result<Klines> {
+        self.klines_pager(symbol, interval, start_time, end_time).collect_all().await
+    }
+
+    /// fapi/v1/premiumIndex
+    /// mark price, index price, 마지막 funding rate, 다음 funding 시간
+    /// `symbol`이 없으면 전체 symbol을 가져온다.
+    async fn get_premium_index(&self, symbol: Option<&str>) -> crate::Result<Vec<PremiumIndex>> {
+        let common_endpoint = CommonEndpoint::PremiumIndex { symbol: symbol.map(|s| s.to_string()) };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        // symbol을 지정하면 배열이 아닌 객체 하나가 온다.
+        let v: Value = serde_json::from_str(res.as_str())?;
+        match v {
+            Value::Array(_) => Ok(from_value(v)?),
+            v => Ok(vec![from_value(v)?]),
+        }
+    }
+
+    /// fapi/v1/fundingRate
+    /// `start_time`부터 `end_time`까지(ms, 포함)의 funding rate를 모두 가져온다. (한 번에 최대 1000개씩 나눠서 요청)
+    async fn get_funding_rate_history(&self, symbol: &str, start_time: u64, end_time: u64) -> crate::Result<Vec<FundingRate>> {
+        const LIMIT: u32 = 1000;
+
+        let mut all: Vec<FundingRate> = Vec::new();
+        let mut start_time = start_time;
+        while start_time <= end_time {
+            let common_endpoint = CommonEndpoint::FundingRate {
+                symbol: Some(symbol.to_string()),
+                start_time: Some(start_time),
+                end_time: Some(end_time),
+                limit: Some(LIMIT),
+            };
+            let res = self.client.execute(common_endpoint).await?;
+            let page: Vec<FundingRate> = serde_json::from_str(res.as_str())?;
+
+            let Some(last) = page.last() else {
+                break;
+            };
+            start_time = last.funding_time + 1;
+            let fetched = page.len();
+            all.extend(page);
+
+            if fetched < LIMIT as usize {
+                break;
+            }
+        }
+
+        Ok(all)
+    }
+
+    /// fapi/v1/fundingInfo
+    async fn get_funding_info(&self) -> crate::Result<Vec<FundingInfo>> {
+        let res = self.client.execute(CommonEndpoint::FundingInfo).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// fapi/v1/openInterest
+    async fn get_open_interest(&self, symbol: &str) -> crate::Result<OpenInterest> {
+        let common_endpoint = CommonEndpoint::OpenInterest { symbol: symbol.to_string() };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// futures/data/openInterestHist
+    async fn get_open_interest_hist(
+        &self,
+        symbol: &str,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> crate::Result<Vec<OpenInterestHist>> {
+        let common_endpoint = CommonEndpoint::OpenInterestHist { symbol: symbol.to_string(), period, start_time, end_time, limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// futures/data/globalLongShortAccountRatio, topLongShortAccountRatio, topLongShortPositionRatio
+    async fn get_long_short_ratio(
+        &self,
+        kind: LongShortRatioKind,
+        symbol: &str,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> crate::Result<Vec<LongShortRatio>> {
+        let common_endpoint = CommonEndpoint::LongShortRatio { kind, symbol: symbol.to_string(), period, start_time, end_time, limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
+
+    /// futures/data/takerlongshortRatio
+    async fn get_taker_buy_sell_volume(
+        &self,
+        symbol: &str,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> crate::Result<Vec<TakerBuySellVolume>> {
+        let common_endpoint = CommonEndpoint::TakerBuySellVolume { symbol: symbol.to_string(), period, start_time, end_time, limit };
+
+        let res = self.client.execute(common_endpoint).await?;
+
+        Ok(serde_json::from_str(res.as_str())?)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapter::mock::{MockRoute, MockTransport},
+        model::binance_model::{LotSize, PriceFilter, SymbolFilter},
+    };
+
+    use super::*;
+
+    const KLINE: &str = r#"[1700000000000,"1.0","2.0","0.5","1.5","100.0",1700000059999,"150.0",10,"60.0","90.0","0"]"#;
+
+    /// fapi/v1/exchangeInfo 응답 일부
+    /// - `BTCUSDT_250926`: 분기물
+    /// - `ETHBTC`: quote asset이 BTC
+    /// - `XEMUSDT`: 정산 중
+    /// - `ALPHAUSDT`: 상장 폐지되어 `contractType`이 비어있음
+    /// - `POSITION_RISK_CONTROL`: 모델에 없는 filter
+    const EXCHANGE_INFO: &str = r#"{
+        "timezone":"UTC","serverTime":1727740800000,"futuresType":"U_MARGINED",
+        "rateLimits":[
+            {"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":2400},
+            {"rateLimitType":"ORDERS","interval":"MINUTE","intervalNum":1,"limit":1200},
+            {"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":300}
+        ],
+        "exchangeFilters":[],
+        "assets":[
+            {"asset":"USDT","marginAvailable":true,"autoAssetExchange":"-10000"},
+            {"asset":"BTC","marginAvailable":true,"autoAssetExchange":null}
+        ],
+        "symbols":[
+            {"symbol":"BTCUSDT","pair":"BTCUSDT","contractType":"PERPETUAL","deliveryDate":4133404800000,"onboardDate":1569398400000,
+             "status":"TRADING","maintMarginPercent":"2.5000","requiredMarginPercent":"5.0000","baseAsset":"BTC","quoteAsset":"USDT",
+             "marginAsset":"USDT","pricePrecision":2,"quantityPrecision":3,"baseAssetPrecision":8,"quotePrecision":8,
+             "underlyingType":"COIN","underlyingSubType":["PoW"],"settlePlan":0,"triggerProtect":"0.0500","liquidationFee":"0.012500",
+             "marketTakeBound":"0.05","maxMoveOrderLimit":10000,
+             "filters":[
+                {"minPrice":"556.80","maxPrice":"4529764","filterType":"PRICE_FILTER","tickSize":"0.10"},
+                {"stepSize":"0.001","filterType":"LOT_SIZE","maxQty":"1000","minQty":"0.001"},
+                {"stepSize":"0.001","filterType":"MARKET_LOT_SIZE","maxQty":"120","minQty":"0.001"},
+                {"limit":200,"filterType":"MAX_NUM_ORDERS"},
+                {"limit":10,"filterType":"MAX_NUM_ALGO_ORDERS"},
+                {"notional":"100","filterType":"MIN_NOTIONAL"},
+                {"multiplierDown":"0.9500","multiplierUp":"1.0500","multiplierDecimal":"4","filterType":"PERCENT_PRICE"},
+                {"positionControlSide":"NONE","filterType":"POSITION_RISK_CONTROL"}
+             ],
+             "orderTypes":["LIMIT","MARKET","STOP","STOP_MARKET","TAKE_PROFIT","TAKE_PROFIT_MARKET","TRAILING_STOP_MARKET"],
+             "timeInForce":["GTC","IOC","FOK","GTX","GTD"],"permissionSets":["GRID","COPY"]},
+            {"symbol":"ETHUSDT","pair":"ETHUSDT","contractType":"PERPETUAL","status":"TRADING","baseAsset":"ETH","quoteAsset":"USDT",
+             "pricePrecision":2,"quantityPrecision":3,"baseAssetPrecision":8,"quotePrecision":8,"filters":[]},
+            {"symbol":"BTCUSDT_250926","pair":"BTCUSDT","contractType":"CURRENT_QUARTER","deliveryDate":1758873600000,"status":"TRADING",
+             "baseAsset":"BTC","quoteAsset":"USDT","pricePrecision":1,"quantityPrecision":3,"baseAssetPrecision":8,"quotePrecision":8,
+             "triggerProtect":"","filters":[]},
+            {"symbol":"ETHBTC","pair":"ETHBTC","contractType":"PERPETUAL","status":"TRADING","baseAsset":"ETH","quoteAsset":"BTC",
+             "pricePrecision":6,"quantityPrecision":3,"baseAssetPrecision":8,"quotePrecision":8,"filters":[]},
+            {"symbol":"XEMUSDT","pair":"XEMUSDT","contractType":"PERPETUAL","status":"SETTLING","baseAsset":"XEM","quoteAsset":"USDT",
+             "pricePrecision":4,"quantityPrecision":0,"baseAssetPrecision":8,"quotePrecision":8,"filters":[]},
+            {"symbol":"ALPHAUSDT","pair":"ALPHAUSDT","contractType":"","status":"PENDING_TRADING","baseAsset":"ALPHA","quoteAsset":"USDT",
+             "pricePrecision":5,"quantityPrecision":0,"baseAssetPrecision":8,"quotePrecision":8,"filters":[]}
+        ]
+    }"#;
+
+    fn common(mock: &MockTransport) -> BinanceCommon<MockTransport> {
+        BinanceCommon::with_client(BinanceClient::builder().build_with_transport(mock.clone()).unwrap())
+    }
+
+    async fn get_kline(body: &str) -> crate::Result<Klines> {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/klines").respond(200, body));
+
+        common(&mock).get_kline("BTCUSDT".to_string(), KlineInterval::Min1, None).await
+    }
+
+    #[tokio::test]
+    async fn parses_kline_rows() {
+        let klines = get_kline(&format!("[{},{}]", KLINE, KLINE)).await.unwrap();
+
+        assert_eq!(klines.kline_list.len(), 2);
+        let kline = &klines.kline_list[1];
+        assert_eq!(kline.open_time, 1700000000000);
+        assert_eq!(kline.close, 1.5);
+        assert_eq!(kline.trade_count, 10);
+        assert_eq!(kline.taker_buy_quote_volume, 90.0);
+        assert_eq!(kline.idx, 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_short_kline_row() {
+        let short = r#"[1700000000000,"1.0","2.0","0.5","1.5","100.0",1700000059999,"150.0",10,"60.0"]"#;
+
+        let error = get_kline(&format!("[{},{}]", KLINE, short)).await.unwrap_err();
+
+        match error {
+            Error::Decode(msg) => assert_eq!(msg, "kline[1]: expected at least 11 fields, got 10"),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_non_numeric_kline_field() {
+        let bad = r#"[1700000000000,"1.0","2.0","0.5","abc","100.0",1700000059999,"150.0",10,"60.0","90.0","0"]"#;
+
+        let error = get_kline(&format!("[{}]", bad)).await.unwrap_err();
+
+        match error {
+            Error::Decode(msg) => assert_eq!(msg, r#"kline[0].close: invalid number "abc""#),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn decodes_kline_error_payload() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/klines").respond(400, r#"{"code":-1121,"msg":"Invalid symbol."}"#));
+
+        let error = common(&mock).get_kline("NOPE".to_string(), KlineInterval::Min1, None).await.unwrap_err();
+
+        assert!(matches!(error, Error::Api { status: 400, code: -1121, .. }));
+        assert!(matches!(get_kline(r#"{"unexpected":true}"#).await, Err(Error::Decode(_))));
+    }
+
+    fn ticker(symbol: &str, quote_volume: &str) -> String {
+        format!(
+            r#"{{"symbol":"{}","priceChange":"1.0","priceChangePercent":"1.0","weightedAvgPrice":"100.0","lastPrice":"101.0","lastQty":"1.0",
+            "openPrice":"100.0","highPrice":"102.0","lowPrice":"99.0","volume":"10.0","quoteVolume":"{}",
+            "openTime":1700000000000,"closeTime":1700086399999,"firstId":1,"lastId":100,"count":100}}"#,
+            symbol, quote_volume,
+        )
+    }
+
+    #[tokio::test]
+    async fn parses_exchange_info_fixture() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/exchangeInfo").respond(200, EXCHANGE_INFO));
+
+        let info = common(&mock).get_exchange_info().await.unwrap();
+
+        assert_eq!(info.rate_limits.len(), 3);
+        assert_eq!(info.assets.len(), 2);
+        assert_eq!(info.assets[0].auto_asset_exchange, Some(-10000.0));
+        assert_eq!(info.assets[1].auto_asset_exchange, None);
+        assert_eq!(info.symbols.len(), 6);
+
+        let btc = info.symbol("BTCUSDT").unwrap();
+        assert_eq!(btc.contract_type, ContractType::Perpetual);
+        assert!(btc.is_trading());
+        assert_eq!((btc.base_asset.as_str(), btc.quote_asset.as_str()), ("BTC", "USDT"));
+        assert_eq!(btc.filters.len(), 8);
+        assert_eq!(btc.filters[7], SymbolFilter::Unknown);
+        assert_eq!(btc.price_filter(), Some(&PriceFilter { min_price: 556.8, max_price: 4529764.0, tick_size: 0.1 }));
+        assert_eq!(btc.lot_size(), Some(&LotSize { min_qty: 0.001, max_qty: 1000.0, step_size: 0.001 }));
+        assert_eq!(btc.market_lot_size().unwrap().max_qty, 120.0);
+        assert_eq!(btc.min_notional().unwrap().notional, 100.0);
+        assert_eq!(btc.percent_price().unwrap().multiplier_decimal, 4);
+        assert_eq!(btc.max_num_orders(), Some(200));
+        assert_eq!(btc.max_num_algo_orders(), Some(10));
+        assert_eq!(btc.trigger_protect, Some(0.05));
+        assert!(btc.supports_order_type("TRAILING_STOP_MARKET"));
+
+        let quarter = info.symbol("BTCUSDT_250926").unwrap();
+        assert_eq!(quarter.contract_type, ContractType::CurrentQuarter);
+        assert_eq!(quarter.trigger_protect, None);
+        assert_eq!(info.symbol("ALPHAUSDT").unwrap().contract_type, ContractType::Unknown);
+        assert_eq!(info.symbol("ALPHAUSDT").unwrap().status, SymbolStatus::PendingTrading);
+    }
+
+    #[tokio::test]
+    async fn queries_symbols_by_quote_status_and_contract() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/exchangeInfo").respond(200, EXCHANGE_INFO));
+        let common = common(&mock);
+        let info = common.get_exchange_info().await.unwrap();
+
+        let symbols = |query: SymbolQuery| -> Vec<String> { info.query(&query).map(|s| s.symbol.clone()).collect() };
+
+        assert_eq!(symbols(SymbolQuery::new().quote_asset("BTC")), ["ETHBTC"]);
+        assert_eq!(symbols(SymbolQuery::new().base_asset("BTC")), ["BTCUSDT", "BTCUSDT_250926"]);
+        assert_eq!(symbols(SymbolQuery::new().status(SymbolStatus::Settling)), ["XEMUSDT"]);
+        assert_eq!(symbols(SymbolQuery::new().quote_asset("USDT").contract_type(ContractType::CurrentQuarter)), ["BTCUSDT_250926"]);
+        assert_eq!(symbols(SymbolQuery::new()).len(), 6);
+        assert_eq!(common.get_symbol_list().await.unwrap(), ["BTCUSDT", "ETHUSDT"]);
+    }
+
+    #[tokio::test]
+    async fn ranks_only_trading_usdt_perpetuals_by_volume() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/exchangeInfo").respond(200, EXCHANGE_INFO));
+        mock.route(MockRoute::get("/fapi/v1/ticker/24hr").respond(200, format!("[{}]", [
+            ticker("BTCUSDT", "1000"),
+            ticker("BTCUSDT_250926", "5000"),
+            ticker("ETHBTC", "3000"),
+            ticker("XEMUSDT", "2000"),
+            ticker("ETHUSDT", "1500"),
+            ticker("NEWUSDT", "9000"),
+        ].join(","))));
+
+        let tickers = common(&mock).get_symbol_with_volume().await.unwrap();
+
+        let symbols: Vec<&str> = tickers.iter().map(|t| t.symbol.as_str()).collect();
+        assert_eq!(symbols, ["ETHUSDT", "BTCUSDT"]);
+    }
+
+    /// 첫 번째 요청의 query string (보낸 순서 그대로)
+    fn sent_query(mock: &MockTransport) -> Vec<(String, String)> {
+        mock.requests()[0].url.query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect()
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[tokio::test]
+    async fn gets_open_interest() {
+        let mock = MockTransport::new();
+        mock.route(
+            MockRoute::get("/fapi/v1/openInterest")
+                .respond(200, r#"{"openInterest":"10659.509","symbol":"BTCUSDT","time":1589437530011}"#),
+        );
+
+        let open_interest = common(&mock).get_open_interest("BTCUSDT").await.unwrap();
+
+        assert_eq!(sent_query(&mock), pairs(&[("symbol", "BTCUSDT")]));
+        assert_eq!(open_interest.symbol, "BTCUSDT");
+        assert_eq!(open_interest.open_interest, 10659.509);
+        assert_eq!(open_interest.time, 1589437530011);
+    }
+
+    #[tokio::test]
+    async fn gets_open_interest_hist() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/futures/data/openInterestHist").respond(200, r#"[
+            {"symbol":"BTCUSDT","sumOpenInterest":"20403.63700000","sumOpenInterestValue":"150570784.07809979",
+             "CMCCirculatingSupply":"165880.538","timestamp":"1583127900000"},
+            {"symbol":"BTCUSDT","sumOpenInterest":"20401.36700000","sumOpenInterestValue":"149940752.14464448",
+             "CMCCirculatingSupply":"165900.14853","timestamp":1583128200000}
+        ]"#));
+
+        let hist = common(&mock)
+            .get_open_interest_hist("BTCUSDT", StatisticsPeriod::Min5, Some(1583127900000), Some(1583128200000), Some(30))
+            .await
+            .unwrap();
+
+        assert_eq!(sent_query(&mock), pairs(&[
+            ("symbol", "BTCUSDT"),
+            ("period", "5m"),
+            ("startTime", "1583127900000"),
+            ("endTime", "1583128200000"),
+            ("limit", "30"),
+        ]));
+        assert_eq!(hist.len(), 2);
+        assert_eq!(hist[0].sum_open_interest, 20403.637);
+        assert!((hist[0].sum_open_interest_value - 150570784.078).abs() < 1e-3);
+        assert_eq!(hist[0].timestamp, 1583127900000);
+        assert_eq!(hist[1].timestamp, 1583128200000);
+    }
+
+    #[tokio::test]
+    async fn gets_long_short_ratio_for_each_kind() {
+        let account = r#"[{"symbol":"BTCUSDT","longShortRatio":"1.9559","longAccount":"0.6617","shortAccount":"0.3383","timestamp":"1583139600000"}]"#;
+        let position = r#"[{"symbol":"BTCUSDT","longShortRatio":"1.9559","longPosition":"0.6617","shortPosition":"0.3383","timestamp":"1583139600000"}]"#;
+
+        for (kind, path, body) in [
+            (LongShortRatioKind::GlobalAccount, "/futures/data/globalLongShortAccountRatio", account),
+            (LongShortRatioKind::TopTraderAccount, "/futures/data/topLongShortAccountRatio", account),
+            (LongShortRatioKind::TopTraderPosition, "/futures/data/topLongShortPositionRatio", position),
+        ] {
+            let mock = MockTransport::new();
+            mock.route(MockRoute::get(path).respond(200, body));
+
+            let ratios = common(&mock).get_long_short_ratio(kind, "BTCUSDT", StatisticsPeriod::Hour4, None, None, None).await.unwrap();
+
+            // 지정하지 않은 시간, limit은 보내지 않는다.
+            assert_eq!(sent_query(&mock), pairs(&[("symbol", "BTCUSDT"), ("period", "4h")]));
+            assert_eq!(ratios.len(), 1);
+            assert_eq!(ratios[0].long_short_ratio, 1.9559);
+            assert_eq!(ratios[0].long, 0.6617);
+            assert_eq!(ratios[0].short, 0.3383);
+            assert_eq!(ratios[0].timestamp, 1583139600000);
+        }
+    }
+
+    #[tokio::test]
+    async fn gets_taker_buy_sell_volume() {
+        let mock = MockTransport::new();
+        mock.route(
+            MockRoute::get("/futures/data/takerlongshortRatio")
+                .respond(200, r#"[{"buySellRatio":"1.5586","buyVol":"387.3300","sellVol":"248.5030","timestamp":"1585614900000"}]"#),
+        );
+
+        let volumes = common(&mock)
+            .get_taker_buy_sell_volume("BTCUSDT", StatisticsPeriod::Day1, Some(1585614900000), None, Some(500))
+            .await
+            .unwrap();
+
+        assert_eq!(sent_query(&mock), pairs(&[("symbol", "BTCUSDT"), ("period", "1d"), ("startTime", "1585614900000"), ("limit", "500")]));
+        assert_eq!(volumes.len(), 1);
+        assert_eq!(volumes[0].buy_sell_ratio, 1.5586);
+        assert_eq!(volumes[0].buy_volume, 387.33);
+        assert_eq!(volumes[0].sell_volume, 248.503);
+        assert_eq!(volumes[0].timestamp, 1585614900000);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_numeric_statistics() {
+        let mock = MockTransport::new();
+        mock.route(
+            MockRoute::get("/futures/data/takerlongshortRatio")
+                .respond(200, r#"[{"buySellRatio":"abc","buyVol":"387.3300","sellVol":"248.5030","timestamp":"1585614900000"}]"#),
+        );
+
+        let result = common(&mock).get_taker_buy_sell_volume("BTCUSDT", StatisticsPeriod::Min5, None, None, None).await;
+
+        assert!(matches!(result, Err(Error::Json(_))));
+    }
+}
@@ -0,0 +1,251 @@
+use std::{env, fmt, path::Path, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::Error;
+
+use super::signer::{Ed25519Signer, HmacSigner, RsaSigner, Signer};
+
+/// drop될 때 메모리를 0으로 덮어쓰는 문자열
+/// `Debug`로 출력해도 값이 노출되지 않는다.
+#[derive(Clone)]
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Secret(Zeroizing::new(value.into()))
+    }
+
+    /// 실제 값이 필요한 곳(서명, header)에서만 사용한다.
+    pub fn expose(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret(***)")
+    }
+}
+
+/// API key 종류
+/// Ed25519, RSA는 `secret_key`에 PKCS#8 PEM private key를 넣는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum KeyType {
+    #[default]
+    Hmac,
+    Ed25519,
+    Rsa,
+}
+
+impl std::str::FromStr for KeyType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "HMAC" => Ok(KeyType::Hmac),
+            "ED25519" => Ok(KeyType::Ed25519),
+            "RSA" => Ok(KeyType::Rsa),
+            _ => Err(Error::Config(format!("unknown key type: {}", s))),
+        }
+    }
+}
+
+/// API key, Secret key(또는 private key) 묶음
+#[derive(Clone)]
+pub struct Credentials {
+    api_key: Secret,
+    secret_key: Secret,
+    key_type: KeyType,
+    signer: Arc<dyn Signer>,
+}
+
+#[derive(Deserialize)]
+struct CredentialsFile {
+    api_key: String,
+    secret_key: String,
+    #[serde(default)]
+    key_type: KeyType,
+}
+
+impl Credentials {
+    /// HMAC key로 만든다.
+    /// key가 비어있으면 `Error::Config`를 반환한다.
+    pub fn new(api_key: impl Into<String>, secret_key: impl Into<String>) -> crate::Result<Self> {
+        Credentials::with_key_type(api_key, secret_key, KeyType::Hmac)
+    }
+
+    /// Ed25519 private key (PKCS#8 PEM)로 만든다.
+    pub fn ed25519(api_key: impl Into<String>, private_key_pem: impl Into<String>) -> crate::Result<Self> {
+        Credentials::with_key_type(api_key, private_key_pem, KeyType::Ed25519)
+    }
+
+    /// RSA private key (PKCS#8 PEM)로 만든다.
+    /// 서명 시간으로 key가 노출될 수 있는 문제(RUSTSEC-2023-0071)는 `RsaSigner`를 참고한다.
+    pub fn rsa(api_key: impl Into<String>, private_key_pem: impl Into<String>) -> crate::Result<Self> {
+        Credentials::with_key_type(api_key, private_key_pem, KeyType::Rsa)
+    }
+
+    pub fn with_key_type(api_key: impl Into<String>, secret_key: impl Into<String>, key_type: KeyType) -> crate::Result<Self> {
+        let api_key = Secret::new(api_key);
+        let secret_key = Secret::new(secret_key);
+
+        if api_key.is_empty() {
+            return Err(Error::Config("api_key is empty".to_string()));
+        }
+        if secret_key.is_empty() {
+            return Err(Error::Config("secret_key is empty".to_string()));
+        }
+
+        let signer: Arc<dyn Signer> = match key_type {
+            KeyType::Hmac => Arc::new(HmacSigner::new(secret_key.clone())),
+            KeyType::Ed25519 => Arc::new(Ed25519Signer::from_pkcs8_pem(secret_key.expose())?),
+            KeyType::Rsa => Arc::new(RsaSigner::from_pkcs8_pem(secret_key.expose())?),
+        };
+
+        Ok(Credentials { api_key, secret_key, key_type, signer })
+    }
+
+    /// `BINANCE_API_KEY`, `BINANCE_SECRET_KEY` 환경변수에서 읽어온다.
+    /// `BINANCE_KEY_TYPE`(HMAC, ED25519, RSA)이 없으면 HMAC으로 본다.
+    pub fn from_env() -> crate::Result<Self> {
+        let api_key = env::var("BINANCE_API_KEY")
+            .map_err(|_| Error::Config("BINANCE_API_KEY is not set".to_string()))?;
+        let secret_key = env::var("BINANCE_SECRET_KEY")
+            .map_err(|_| Error::Config("BINANCE_SECRET_KEY is not set".to_string()))?;
+        let key_type = match env::var("BINANCE_KEY_TYPE") {
+            Ok(key_type) => key_type.parse()?,
+            Err(_) => KeyType::Hmac,
+        };
+
+        Credentials::with_key_type(api_key, secret_key, key_type)
+    }
+
+    /// json 설정 파일에서 읽어온다.
+    /// `key_type`이 없으면 `from_env`처럼 HMAC으로 보고, 모르는 `key_type`이면 `Error::Config`를 반환한다.
+    /// ```json
+    /// { "api_key": "...", "secret_key": "...", "key_type": "HMAC" }
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let text = Zeroizing::new(
+            std::fs::read_to_string(path)
+                .map_err(|e| Error::Config(format!("fail to read {}: {}", path.display(), e)))?,
+        );
+        let file: CredentialsFile = serde_json::from_str(&text)
+            .map_err(|e| Error::Config(format!("fail to parse {}: {}", path.display(), e)))?;
+        let file = Zeroizing::new(file);
+
+        Credentials::with_key_type(file.api_key.as_str(), file.secret_key.as_str(), file.key_type)
+    }
+
+    pub fn api_key(&self) -> &str {
+        self.api_key.expose()
+    }
+
+    /// HMAC secret key 또는 PEM private key
+    pub fn secret_key(&self) -> &str {
+        self.secret_key.expose()
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    pub fn signer(&self) -> &dyn Signer {
+        self.signer.as_ref()
+    }
+
+    /// key 종류에 맞는 방식으로 payload를 서명한다.
+    pub fn sign(&self, payload: &str) -> crate::Result<String> {
+        self.signer.sign(payload.as_bytes())
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("secret_key", &self.secret_key)
+            .field("key_type", &self.key_type)
+            .finish()
+    }
+}
+
+impl zeroize::Zeroize for CredentialsFile {
+    fn zeroize(&mut self) {
+        self.api_key.zeroize();
+        self.secret_key.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// 테스트마다 다른 임시 파일에 `content`를 쓴다.
+    fn write_file(name: &str, content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("credentials-{}-{}.json", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn load(name: &str, content: &str) -> crate::Result<Credentials> {
+        let path = write_file(name, content);
+        let credentials = Credentials::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        credentials
+    }
+
+    #[test]
+    fn parses_key_type_case_insensitively() {
+        assert_eq!("hmac".parse::<KeyType>().unwrap(), KeyType::Hmac);
+        assert_eq!("Ed25519".parse::<KeyType>().unwrap(), KeyType::Ed25519);
+        assert_eq!("RSA".parse::<KeyType>().unwrap(), KeyType::Rsa);
+        assert!(matches!("DSA".parse::<KeyType>(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn loads_file_with_key_type() {
+        let credentials = load("hmac", r#"{"api_key":"api","secret_key":"secret","key_type":"HMAC"}"#).unwrap();
+
+        assert_eq!(credentials.api_key(), "api");
+        assert_eq!(credentials.secret_key(), "secret");
+        assert_eq!(credentials.key_type(), KeyType::Hmac);
+    }
+
+    #[test]
+    fn defaults_missing_key_type_to_hmac() {
+        let credentials = load("missing", r#"{"api_key":"api","secret_key":"secret"}"#).unwrap();
+
+        assert_eq!(credentials.key_type(), KeyType::Hmac);
+    }
+
+    #[test]
+    fn rejects_unknown_key_type_with_path() {
+        let path = write_file("unknown", r#"{"api_key":"api","secret_key":"secret","key_type":"DSA"}"#);
+
+        let error = Credentials::from_file(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        match error {
+            Error::Config(msg) => assert!(msg.contains(&path.display().to_string()), "{}", msg),
+            e => panic!("expected config error, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_file() {
+        assert!(matches!(load("invalid", "{not json"), Err(Error::Config(_))));
+        assert!(matches!(load("empty_key", r#"{"api_key":"","secret_key":"secret"}"#), Err(Error::Config(_))));
+        assert!(matches!(Credentials::from_file("/nonexistent/credentials.json"), Err(Error::Config(_))));
+    }
+}
@@ -0,0 +1,204 @@
+use std::path::Path;
+
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::Error;
+
+use super::credentials::{Credentials, KeyType};
+
+const VERSION: u32 = 1;
+const ITERATIONS: u32 = 600_000;
+
+/// 비밀번호로 암호화한 key 파일
+/// PBKDF2-HMAC-SHA256으로 비밀번호에서 key를 만들고 AES-256-GCM으로 암호화한다.
+/// # Example
+/// ```no_run
+/// use crypto_trading::adapter::{credentials::Credentials, keystore::Keystore};
+///
+/// let credentials = Credentials::new("api_key", "secret_key").unwrap();
+/// Keystore::encrypt(&credentials, "password").unwrap().save("binance.keystore").unwrap();
+///
+/// let credentials = Credentials::from_keystore("binance.keystore", "password").unwrap();
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u32,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Keystore {
+    pub fn encrypt(credentials: &Credentials, password: &str) -> crate::Result<Self> {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+        let plaintext = Zeroizing::new(serde_json::to_string(&KeystoreContentRef {
+            api_key: credentials.api_key(),
+            secret_key: credentials.secret_key(),
+            key_type: credentials.key_type(),
+        })?);
+
+        let cipher = cipher(password, &salt, ITERATIONS)?;
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| Error::Config("fail to encrypt keystore".to_string()))?;
+
+        Ok(Keystore {
+            version: VERSION,
+            iterations: ITERATIONS,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// 비밀번호가 틀리거나 파일이 변조되었으면 `Error::Config`를 반환한다.
+    pub fn decrypt(&self, password: &str) -> crate::Result<Credentials> {
+        if self.version != VERSION {
+            return Err(Error::Config(format!("unsupported keystore version: {}", self.version)));
+        }
+
+        let salt = decode_hex(&self.salt)?;
+        let nonce = decode_hex(&self.nonce)?;
+        let ciphertext = decode_hex(&self.ciphertext)?;
+        if nonce.len() != 12 {
+            return Err(Error::Config("invalid keystore nonce".to_string()));
+        }
+
+        let cipher = cipher(password, &salt, self.iterations)?;
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| Error::Config("fail to decrypt keystore (wrong password?)".to_string()))?,
+        );
+
+        // serde 에러 메시지에 key 값이 들어갈 수 있으므로 내용은 남기지 않는다.
+        let content: KeystoreContent = serde_json::from_slice(&plaintext)
+            .map_err(|_| Error::Config("invalid keystore content (unknown key_type?)".to_string()))?;
+        let content = Zeroizing::new(content);
+
+        Credentials::with_key_type(content.api_key.as_str(), content.secret_key.as_str(), content.key_type)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("fail to read {}: {}", path.display(), e)))?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let text = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, text)
+            .map_err(|e| Error::Config(format!("fail to write {}: {}", path.display(), e)))
+    }
+}
+
+/// 암호화되는 내용
+#[derive(Serialize)]
+struct KeystoreContentRef<'a> {
+    api_key: &'a str,
+    secret_key: &'a str,
+    key_type: KeyType,
+}
+
+/// `key_type`이 없으면 `Credentials::from_file`처럼 HMAC으로 본다.
+#[derive(Deserialize)]
+struct KeystoreContent {
+    api_key: String,
+    secret_key: String,
+    #[serde(default)]
+    key_type: KeyType,
+}
+
+impl zeroize::Zeroize for KeystoreContent {
+    fn zeroize(&mut self) {
+        self.api_key.zeroize();
+        self.secret_key.zeroize();
+    }
+}
+
+impl Credentials {
+    /// 암호화된 keystore 파일에서 읽어온다.
+    pub fn from_keystore(path: impl AsRef<Path>, password: &str) -> crate::Result<Self> {
+        Keystore::load(path)?.decrypt(password)
+    }
+}
+
+fn cipher(password: &str, salt: &[u8], iterations: u32) -> crate::Result<Aes256Gcm> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, key.as_mut());
+    Aes256Gcm::new_from_slice(key.as_ref())
+        .map_err(|_| Error::Config("invalid keystore key".to_string()))
+}
+
+fn decode_hex(value: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(value).map_err(|_| Error::Config("invalid keystore format".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트가 느려지지 않도록 반복 횟수를 1로 암호화한다.
+    fn keystore(plaintext: &str, password: &str) -> Keystore {
+        let salt = [7u8; 16];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher(password, &salt, 1).unwrap().encrypt(&nonce, plaintext.as_bytes()).unwrap();
+
+        Keystore {
+            version: VERSION,
+            iterations: 1,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        }
+    }
+
+    #[test]
+    fn decrypts_credentials() {
+        let keystore = keystore(r#"{"api_key":"api","secret_key":"secret","key_type":"HMAC"}"#, "password");
+
+        let credentials = keystore.decrypt("password").unwrap();
+        assert_eq!(credentials.api_key(), "api");
+        assert_eq!(credentials.secret_key(), "secret");
+        assert_eq!(credentials.key_type(), KeyType::Hmac);
+    }
+
+    #[test]
+    fn rejects_wrong_password() {
+        let keystore = keystore(r#"{"api_key":"api","secret_key":"secret","key_type":"HMAC"}"#, "password");
+
+        assert!(matches!(keystore.decrypt("wrong"), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn defaults_missing_key_type_to_hmac() {
+        let keystore = keystore(r#"{"api_key":"api","secret_key":"secret"}"#, "password");
+
+        assert_eq!(keystore.decrypt("password").unwrap().key_type(), KeyType::Hmac);
+    }
+
+    #[test]
+    fn rejects_unknown_key_type() {
+        let unknown = keystore(r#"{"api_key":"api","secret_key":"secret","key_type":"DSA"}"#, "password");
+        let corrupt = keystore(r#"{"api_key":"api","secret_key":"secret","key_type":1}"#, "password");
+
+        for keystore in [unknown, corrupt] {
+            match keystore.decrypt("password") {
+                Err(Error::Config(msg)) => assert!(!msg.contains("secret")),
+                other => panic!("expected config error, got {:?}", other.map(|c| c.key_type())),
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::{Arc, Mutex};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method,
+};
+
+use crate::Error;
+
+use super::transport::{HttpRequest, HttpResponse, Transport};
+
+/// 네트워크 없이 미리 넣어둔 응답을 돌려주는 transport
+/// method, path, query가 맞는 첫 번째 route의 응답을 반환한다.
+/// 맞는 route가 없으면 `Error::Config`를 반환한다.
+/// # Example
+/// ```
+/// use crypto_trading::adapter::{client::BinanceClient, common::BinanceCommon, mock::{MockRoute, MockTransport}};
+/// use crypto_trading::{model::binance_model::KlineInterval, port::binance_port::CommonPort};
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let mock = MockTransport::new();
+/// mock.route(
+///     MockRoute::get("/fapi/v1/klines")
+///         .query("symbol", "BTCUSDT")
+///         .respond(200, r#"[[1700000000000,"1.0","2.0","0.5","1.5","100.0",1700000059999,"150.0",10,"60.0","90.0","0"]]"#),
+/// );
+///
+/// let client = BinanceClient::builder().build_with_transport(mock.clone()).unwrap();
+/// let common = BinanceCommon::with_client(client);
+///
+/// let klines = common.get_kline("BTCUSDT".to_string(), KlineInterval::Min1, Some(1)).await.unwrap();
+/// assert_eq!(klines.kline_list.len(), 1);
+/// assert_eq!(mock.requests().len(), 1);
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    inner: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// 먼저 추가한 route가 우선한다.
+    pub fn route(&self, route: MockRoute) -> &Self {
+        self.inner.lock().unwrap().routes.push(route);
+        self
+    }
+
+    /// 지금까지 받은 요청
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.inner.lock().unwrap();
+        state.routes.clear();
+        state.requests.clear();
+    }
+}
+
+impl Transport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+        let mut state = self.inner.lock().unwrap();
+        state.requests.push(request.clone());
+
+        let idx = state.routes
+            .iter()
+            .position(|route| route.matches(&request))
+            .ok_or_else(|| Error::Config(format!("no mock route for {} {}", request.method, request.url.path())))?;
+
+        let route = &mut state.routes[idx];
+        let response = route.response.clone();
+        if let Some(times) = route.times.as_mut() {
+            *times -= 1;
+            if *times == 0 {
+                state.routes.remove(idx);
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+/// `MockTransport`의 요청 조건과 응답
+/// `query`는 query string과 form body에서 찾는다. 지정한 파라미터만 비교하므로 timestamp, signature처럼 매번 바뀌는 값은 무시된다.
+#[derive(Debug, Clone)]
+pub struct MockRoute {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    response: HttpResponse,
+    times: Option<usize>,
+}
+
+impl MockRoute {
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        MockRoute {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            response: HttpResponse { status: 200, headers: HeaderMap::new(), body: String::new() },
+            times: None,
+        }
+    }
+
+    pub fn get(path: impl Into<String>) -> Self {
+        MockRoute::new(Method::GET, path)
+    }
+
+    pub fn post(path: impl Into<String>) -> Self {
+        MockRoute::new(Method::POST, path)
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        MockRoute::new(Method::DELETE, path)
+    }
+
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn respond(mut self, status: u16, body: impl Into<String>) -> Self {
+        self.response.status = status;
+        self.response.body = body.into();
+        self
+    }
+
+    /// 응답 header (예: `X-MBX-USED-WEIGHT-1M`)
+    /// 잘못된 header 이름이나 값이면 panic한다.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = HeaderName::from_bytes(name.as_bytes()).expect("invalid header name");
+        let value = HeaderValue::from_str(value).expect("invalid header value");
+        self.response.headers.insert(name, value);
+        self
+    }
+
+    /// `times`번 응답한 뒤 route를 지운다. (재시도 테스트 등에 사용)
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times.max(1));
+        self
+    }
+
+    fn matches(&self, request: &HttpRequest) -> bool {
+        if self.method != request.method || self.path != request.url.path() {
+            return false;
+        }
+
+        let body = url::form_urlencoded::parse(request.body.as_deref().unwrap_or_default().as_bytes());
+        let pairs: Vec<(String, String)> = request.url
+            .query_pairs()
+            .chain(body)
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        self.query.iter().all(|q| pairs.contains(q))
+    }
+}
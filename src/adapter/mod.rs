@@ -1,4 +1,17 @@
 pub mod common;
 pub mod trade;
 pub mod users;
+pub mod client;
+pub mod credentials;
+pub mod signer;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod rate_limit;
+pub mod retry;
+pub mod clock;
+pub mod transport;
+pub mod mock;
+pub mod cassette;
+pub mod pager;
+pub mod symbol_rules;
 mod adapter_utils;
\ No newline at end of file
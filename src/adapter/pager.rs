@@ -0,0 +1,373 @@
+use crate::{model::binance_model::{AggTrade, Kline, KlineInterval, Klines}, port::binance_port::CommonPort};
+
+use super::{common::BinanceCommon, transport::Transport};
+
+/// aggTrades는 `startTime`, `endTime`을 같이 보낼 때 1시간 이내여야 한다.
+const AGG_TRADES_WINDOW: u64 = 60 * 60 * 1000;
+const AGG_TRADES_LIMIT: u32 = 1000;
+/// weight 5로 가장 많이 가져올 수 있는 개수
+const KLINES_LIMIT: i32 = 1000;
+
+/// 긴 기간의 aggTrade를 page 단위로 가져온다.
+/// 처음에는 시간으로 찾고, 그 다음부터는 마지막 id 다음(`fromId`)부터 이어서 가져온다.
+/// # Example
+/// ```no_run
+/// use crypto_trading::adapter::common::BinanceCommon;
+///
+/// # async fn run() -> crypto_trading::Result<()> {
+/// let common = BinanceCommon::new();
+/// let mut pager = common.agg_trades_pager("BTCUSDT", 1_700_000_000_000, 1_700_086_400_000);
+/// while let Some(trades) = pager.next_page().await? {
+///     println!("{}", trades.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AggTradePager<'a, R: Transport> {
+    common: &'a BinanceCommon<R>,
+    symbol: String,
+    start_time: u64,
+    end_time: u64,
+    next_id: Option<u64>,
+    done: bool,
+}
+
+impl<'a, R: Transport> AggTradePager<'a, R> {
+    pub fn new(common: &'a BinanceCommon<R>, symbol: &str, start_time: u64, end_time: u64) -> Self {
+        AggTradePager {
+            common,
+            symbol: symbol.to_string(),
+            start_time,
+            end_time,
+            next_id: None,
+            done: start_time > end_time,
+        }
+    }
+
+    /// 다음 page (최대 1000개)
+    /// 기간 안의 trade를 모두 가져왔으면 `None`을 반환한다.
+    pub async fn next_page(&mut self) -> crate::Result<Option<Vec<AggTrade>>> {
+        while !self.done {
+            let by_id = self.next_id.is_some();
+            let trades = match self.next_id {
+                Some(from_id) => {
+                    self.common
+                        .get_agg_trades(&self.symbol, Some(from_id), None, None, Some(AGG_TRADES_LIMIT))
+                        .await?
+                },
+                None => {
+                    // 거래가 없는 구간은 1시간씩 건너뛴다.
+                    let window_end = self.end_time.min(self.start_time.saturating_add(AGG_TRADES_WINDOW - 1));
+                    let trades = self.common
+                        .get_agg_trades(&self.symbol, None, Some(self.start_time), Some(window_end), Some(AGG_TRADES_LIMIT))
+                        .await?;
+                    if trades.is_empty() {
+                        self.start_time = window_end + 1;
+                        self.done = self.start_time > self.end_time;
+                        continue;
+                    }
+                    trades
+                },
+            };
+
+            let Some(last) = trades.last() else {
+                self.done = true;
+                break;
+            };
+            self.next_id = Some(last.agg_trade_id + 1);
+
+            // fromId로 가져온 page가 limit보다 작으면 더 이상 trade가 없다.
+            let fetched = trades.len();
+            let trades: Vec<AggTrade> = trades.into_iter().filter(|t| t.time <= self.end_time).collect();
+            if trades.len() < fetched || (by_id && fetched < AGG_TRADES_LIMIT as usize) {
+                self.done = true;
+            }
+            if trades.is_empty() {
+                break;
+            }
+            return Ok(Some(trades));
+        }
+
+        Ok(None)
+    }
+
+    /// 남은 page를 모두 가져와 합친다.
+    pub async fn collect_all(mut self) -> crate::Result<Vec<AggTrade>> {
+        let mut all = Vec::new();
+        while let Some(trades) = self.next_page().await? {
+            all.extend(trades);
+        }
+        Ok(all)
+    }
+}
+
+/// 긴 기간의 kline을 page 단위로 가져온다.
+/// 마지막 kline의 open time 다음부터 이어서 요청하므로 page 사이에 중복이나 빈 구간이 없다.
+/// # Example
+/// ```no_run
+/// use crypto_trading::{adapter::common::BinanceCommon, model::binance_model::KlineInterval};
+///
+/// # async fn run() -> crypto_trading::Result<()> {
+/// let common = BinanceCommon::new();
+/// let mut pager = common.klines_pager("BTCUSDT", KlineInterval::Min1, 1_600_000_000_000, 1_700_000_000_000);
+/// while let Some(klines) = pager.next_page().await? {
+///     println!("{}", klines.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct KlinePager<'a, R: Transport> {
+    common: &'a BinanceCommon<R>,
+    symbol: String,
+    interval: KlineInterval,
+    start_time: u64,
+    end_time: u64,
+    done: bool,
+}
+
+impl<'a, R: Transport> KlinePager<'a, R> {
+    pub fn new(common: &'a BinanceCommon<R>, symbol: &str, interval: KlineInterval, start_time: u64, end_time: u64) -> Self {
+        KlinePager {
+            common,
+            symbol: symbol.to_string(),
+            interval,
+            start_time,
+            end_time,
+            done: start_time > end_time,
+        }
+    }
+
+    /// 다음 page (최대 1000개)
+    /// 기간 안의 kline을 모두 가져왔으면 `None`을 반환한다.
+    /// `Kline::idx`는 page 안에서의 순서이다.
+    pub async fn next_page(&mut self) -> crate::Result<Option<Vec<Kline>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let klines = self.common
+            .get_kline_page(&self.symbol, self.interval, self.start_time, self.end_time, KLINES_LIMIT)
+            .await?;
+
+        let klines: Vec<Kline> = klines
+            .into_iter()
+            .filter(|k| k.open_time >= self.start_time && k.open_time <= self.end_time)
+            .collect();
+
+        match klines.last() {
+            Some(last) if klines.len() == KLINES_LIMIT as usize => {
+                self.start_time = last.open_time + 1;
+                self.done = self.start_time > self.end_time;
+            },
+            Some(_) => self.done = true,
+            None => {
+                self.done = true;
+                return Ok(None);
+            },
+        }
+
+        Ok(Some(klines))
+    }
+
+    /// 남은 page를 모두 가져와 하나의 `Klines`로 합친다.
+    /// `Kline::idx`는 전체에서의 순서로 다시 매긴다.
+    pub async fn collect_all(mut self) -> crate::Result<Klines> {
+        let mut all: Vec<Kline> = Vec::new();
+        while let Some(klines) = self.next_page().await? {
+            all.extend(klines);
+        }
+
+        for (i, kline) in all.iter_mut().enumerate() {
+            kline.idx = i as u64;
+        }
+        Ok(Klines::new(all))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::{client::BinanceClient, mock::{MockRoute, MockTransport}, transport::HttpRequest};
+
+    use super::*;
+
+    /// 2023-11-14 22:13:00 UTC (분 경계)
+    const START: u64 = 1_699_999_980_000;
+    const MINUTE: u64 = 60_000;
+    const HOUR: u64 = 60 * MINUTE;
+
+    fn common(mock: &MockTransport) -> BinanceCommon<MockTransport> {
+        BinanceCommon::with_client(BinanceClient::builder().build_with_transport(mock.clone()).unwrap())
+    }
+
+    /// `from`부터 1분 간격의 kline `count`개
+    fn klines(from: u64, count: u64) -> String {
+        let rows: Vec<String> = (0..count)
+            .map(|i| {
+                let t = from + i * MINUTE;
+                format!(r#"[{},"1.0","2.0","0.5","1.5","100.0",{},"150.0",10,"60.0","90.0","0"]"#, t, t + MINUTE - 1)
+            })
+            .collect();
+        format!("[{}]", rows.join(","))
+    }
+
+    /// (id, time) 목록의 aggTrade
+    fn agg_trades(trades: &[(u64, u64)]) -> String {
+        let rows: Vec<String> = trades
+            .iter()
+            .map(|(id, time)| format!(r#"{{"a":{},"p":"100.0","q":"1.0","f":{},"l":{},"T":{},"m":true}}"#, id, id, id, time))
+            .collect();
+        format!("[{}]", rows.join(","))
+    }
+
+    fn param(request: &HttpRequest, key: &str) -> Option<String> {
+        request.url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+
+    fn params(mock: &MockTransport, key: &str) -> Vec<Option<String>> {
+        mock.requests().iter().map(|r| param(r, key)).collect()
+    }
+
+    #[tokio::test]
+    async fn stitches_kline_pages_without_gaps() {
+        let mock = MockTransport::new();
+        let second_start = START + 999 * MINUTE + 1;
+        mock.route(MockRoute::get("/fapi/v1/klines").query("startTime", START.to_string()).respond(200, klines(START, 1000)));
+        mock.route(MockRoute::get("/fapi/v1/klines").query("startTime", second_start.to_string()).respond(200, klines(START + 1000 * MINUTE, 500)));
+        let common = common(&mock);
+
+        let klines = common.klines_pager("BTCUSDT", KlineInterval::Min1, START, START + 1499 * MINUTE).collect_all().await.unwrap();
+
+        assert_eq!(params(&mock, "startTime"), vec![Some(START.to_string()), Some(second_start.to_string())]);
+        assert_eq!(params(&mock, "limit"), vec![Some("1000".to_string()); 2]);
+        assert_eq!(klines.kline_list.len(), 1500);
+        for (i, kline) in klines.kline_list.iter().enumerate() {
+            assert_eq!(kline.open_time, START + i as u64 * MINUTE);
+            assert_eq!(kline.idx, i as u64);
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_after_full_page_at_end_boundary() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/klines").respond(200, klines(START, 1000)).times(1));
+        let common = common(&mock);
+        let mut pager = common.klines_pager("BTCUSDT", KlineInterval::Min1, START, START + 999 * MINUTE);
+
+        assert_eq!(pager.next_page().await.unwrap().unwrap().len(), 1000);
+        assert!(pager.next_page().await.unwrap().is_none());
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn stops_after_short_kline_page() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/klines").respond(200, klines(START, 10)).times(1));
+        let common = common(&mock);
+
+        let klines = common.klines_pager("BTCUSDT", KlineInterval::Min1, START, START + 5000 * MINUTE).collect_all().await.unwrap();
+
+        assert_eq!(klines.kline_list.len(), 10);
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn returns_no_klines_for_empty_range() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/klines").respond(200, "[]"));
+        let common = common(&mock);
+
+        // 시작이 끝보다 뒤면 요청하지 않는다.
+        let mut pager = common.klines_pager("BTCUSDT", KlineInterval::Min1, START + MINUTE, START);
+        assert!(pager.next_page().await.unwrap().is_none());
+        assert!(mock.requests().is_empty());
+
+        // kline이 없는 구간
+        let klines = common.klines_pager("BTCUSDT", KlineInterval::Min1, START, START + 10 * MINUTE).collect_all().await.unwrap();
+        assert!(klines.kline_list.is_empty());
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_empty_agg_trade_windows() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("startTime", START.to_string()).respond(200, "[]"));
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("startTime", (START + HOUR).to_string()).respond(200, "[]"));
+        mock.route(
+            MockRoute::get("/fapi/v1/aggTrades")
+                .query("startTime", (START + 2 * HOUR).to_string())
+                .respond(200, agg_trades(&[(10, START + 2 * HOUR + 5), (11, START + 2 * HOUR + 9)])),
+        );
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("fromId", "12").respond(200, "[]"));
+        let common = common(&mock);
+
+        let trades = common.agg_trades_pager("BTCUSDT", START, START + 3 * HOUR - 1).collect_all().await.unwrap();
+
+        let ids: Vec<u64> = trades.iter().map(|t| t.agg_trade_id).collect();
+        assert_eq!(ids, vec![10, 11]);
+        assert_eq!(params(&mock, "startTime"), vec![
+            Some(START.to_string()),
+            Some((START + HOUR).to_string()),
+            Some((START + 2 * HOUR).to_string()),
+            None,
+        ]);
+        assert_eq!(params(&mock, "endTime"), vec![
+            Some((START + HOUR - 1).to_string()),
+            Some((START + 2 * HOUR - 1).to_string()),
+            Some((START + 3 * HOUR - 1).to_string()),
+            None,
+        ]);
+    }
+
+    #[tokio::test]
+    async fn stops_when_all_agg_trade_windows_are_empty() {
+        let mock = MockTransport::new();
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").respond(200, "[]"));
+        let common = common(&mock);
+
+        let trades = common.agg_trades_pager("BTCUSDT", START, START + 2 * HOUR - 1).collect_all().await.unwrap();
+
+        assert!(trades.is_empty());
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn pages_agg_trades_by_id_after_first_window() {
+        let mock = MockTransport::new();
+        let first: Vec<(u64, u64)> = (1..=1000).map(|id| (id, START + id)).collect();
+        let second: Vec<(u64, u64)> = (1001..=1003).map(|id| (id, START + id)).collect();
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("startTime", START.to_string()).respond(200, agg_trades(&first)));
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("fromId", "1001").respond(200, agg_trades(&second)));
+        let common = common(&mock);
+        let mut pager = common.agg_trades_pager("BTCUSDT", START, START + 10 * HOUR);
+
+        assert_eq!(pager.next_page().await.unwrap().unwrap().len(), 1000);
+        assert_eq!(pager.next_page().await.unwrap().unwrap().len(), 3);
+        // fromId로 가져온 page가 limit보다 작으므로 더 요청하지 않는다.
+        assert!(pager.next_page().await.unwrap().is_none());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(param(&requests[1], "fromId").as_deref(), Some("1001"));
+        assert_eq!(param(&requests[1], "startTime"), None);
+        assert_eq!(param(&requests[1], "endTime"), None);
+    }
+
+    #[tokio::test]
+    async fn drops_agg_trades_after_end_time() {
+        let mock = MockTransport::new();
+        let end_time = START + 1500;
+        let first: Vec<(u64, u64)> = (1..=1000).map(|id| (id, START + id)).collect();
+        let second: Vec<(u64, u64)> = (1001..=2000).map(|id| (id, START + id)).collect();
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("startTime", START.to_string()).respond(200, agg_trades(&first)));
+        mock.route(MockRoute::get("/fapi/v1/aggTrades").query("fromId", "1001").respond(200, agg_trades(&second)));
+        let common = common(&mock);
+
+        let trades = common.agg_trades_pager("BTCUSDT", START, end_time).collect_all().await.unwrap();
+
+        assert_eq!(trades.len(), 1500);
+        assert_eq!(trades.last().unwrap().time, end_time);
+        assert!(trades.windows(2).all(|w| w[1].agg_trade_id == w[0].agg_trade_id + 1));
+        // 기간을 넘는 trade를 받았으면 다음 page를 요청하지 않는다.
+        assert_eq!(mock.requests().len(), 2);
+    }
+}
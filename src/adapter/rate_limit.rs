@@ -0,0 +1,429 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::header::HeaderMap;
+
+use crate::model::binance_model::RateLimit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKind {
+    RequestWeight,
+    Orders,
+}
+
+#[derive(Debug, Clone)]
+struct Window {
+    kind: RateLimitKind,
+    interval_ms: u64,
+    limit: u32,
+    window_id: u64,
+    used: u32,
+}
+
+impl Window {
+    fn new(kind: RateLimitKind, interval_ms: u64, limit: u32) -> Self {
+        Window { kind, interval_ms, limit, window_id: 0, used: 0 }
+    }
+
+    /// 현재 시간이 새로운 window에 들어갔으면 사용량을 초기화한다.
+    fn roll(&mut self, now_ms: u64) {
+        let window_id = now_ms / self.interval_ms;
+        if window_id != self.window_id {
+            self.window_id = window_id;
+            self.used = 0;
+        }
+    }
+
+    fn until_next_window(&self, now_ms: u64) -> Duration {
+        let next = (now_ms / self.interval_ms + 1) * self.interval_ms;
+        Duration::from_millis(next - now_ms)
+    }
+}
+
+struct State {
+    windows: Vec<Window>,
+    blocked_until: Option<Instant>,
+}
+
+/// 모든 adapter가 공유하는 request weight, order count 제한기
+/// 요청 전에 `acquire`로 예산을 확보하고, 응답의 `X-MBX-USED-WEIGHT-*`, `X-MBX-ORDER-COUNT-*` header로 사용량을 갱신한다.
+/// 예산이 부족하면 다음 window가 시작될 때까지 기다린다.
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    /// futures 기본 제한 (exchangeInfo를 받아오기 전에 사용)
+    /// - REQUEST_WEIGHT: 2400 / 1m
+    /// - ORDERS: 1200 / 1m, 300 / 10s
+    pub fn new() -> Self {
+        RateLimiter {
+            state: Mutex::new(State {
+                windows: default_windows(),
+                blocked_until: None,
+            }),
+        }
+    }
+
+    /// exchangeInfo의 `rateLimits`로 제한을 다시 설정한다.
+    /// 길이가 0인 interval은 무시하고, `rateLimits`에 없는 종류는 기본 제한을 그대로 사용한다.
+    pub fn configure(&self, rate_limits: &[RateLimit]) {
+        let mut windows: Vec<Window> = rate_limits
+            .iter()
+            .filter_map(|r| {
+                let kind = match r.rate_limit_type.as_str() {
+                    "REQUEST_WEIGHT" => RateLimitKind::RequestWeight,
+                    "ORDERS" => RateLimitKind::Orders,
+                    _ => return None,
+                };
+                let interval_ms = interval_to_ms(&r.interval)? * r.interval_num;
+                (interval_ms > 0).then(|| Window::new(kind, interval_ms, r.limit))
+            })
+            .collect();
+
+        for kind in [RateLimitKind::RequestWeight, RateLimitKind::Orders] {
+            if !windows.iter().any(|w| w.kind == kind) {
+                windows.extend(default_windows().into_iter().filter(|w| w.kind == kind));
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.windows = windows;
+    }
+
+    /// `weight`만큼의 예산을 확보할 때까지 기다린다.
+    /// `is_order`가 true면 ORDERS 제한에도 1을 더한다.
+    pub async fn acquire(&self, weight: u32, is_order: bool) {
+        loop {
+            let wait = self.try_acquire(weight, is_order);
+            match wait {
+                Some(wait) => {
+                    tracing::debug!(wait_ms = wait.as_millis() as u64, weight, is_order, "rate limit reached, waiting");
+                    tokio::time::sleep(wait).await
+                },
+                None => return,
+            }
+        }
+    }
+
+    fn try_acquire(&self, weight: u32, is_order: bool) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(until) = state.blocked_until {
+            let now = Instant::now();
+            if until > now {
+                return Some(until - now);
+            }
+            state.blocked_until = None;
+        }
+
+        let now_ms = now_ms();
+        let mut wait: Option<Duration> = None;
+        for window in state.windows.iter_mut() {
+            window.roll(now_ms);
+            let cost = cost(window.kind, weight, is_order);
+            // 한 번에 limit보다 큰 요청은 window가 비어있을 때 보낸다.
+            if cost > 0 && window.used > 0 && window.used + cost > window.limit {
+                let until = window.until_next_window(now_ms);
+                wait = Some(wait.map_or(until, |w| w.max(until)));
+            }
+        }
+
+        if wait.is_some() {
+            return wait;
+        }
+
+        for window in state.windows.iter_mut() {
+            window.used += cost(window.kind, weight, is_order);
+        }
+        None
+    }
+
+    /// 응답 header에 있는 실제 사용량으로 갱신한다.
+    pub fn update_from_headers(&self, headers: &HeaderMap) {
+        let mut state = self.state.lock().unwrap();
+        let now_ms = now_ms();
+
+        for (name, value) in headers.iter() {
+            let name = name.as_str();
+            let (kind, interval) = if let Some(interval) = name.strip_prefix("x-mbx-used-weight-") {
+                (RateLimitKind::RequestWeight, interval)
+            } else if let Some(interval) = name.strip_prefix("x-mbx-order-count-") {
+                (RateLimitKind::Orders, interval)
+            } else {
+                continue;
+            };
+
+            let Some(interval_ms) = header_interval_to_ms(interval) else { continue };
+            let Some(used) = value.to_str().ok().and_then(|v| v.parse::<u32>().ok()) else { continue };
+
+            if let Some(window) = state.windows.iter_mut().find(|w| w.kind == kind && w.interval_ms == interval_ms) {
+                window.roll(now_ms);
+                window.used = used;
+            }
+        }
+    }
+
+    /// 429, 418 응답을 받으면 `retry_after` 동안 모든 요청을 멈춘다.
+    pub fn block_for(&self, retry_after: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let until = Instant::now() + retry_after;
+        state.blocked_until = Some(state.blocked_until.map_or(until, |u| u.max(until)));
+    }
+
+    /// 현재 window에서 사용한 request weight
+    pub fn used_weight(&self) -> u32 {
+        let mut state = self.state.lock().unwrap();
+        let now_ms = now_ms();
+        state.windows
+            .iter_mut()
+            .filter(|w| w.kind == RateLimitKind::RequestWeight)
+            .map(|w| {
+                w.roll(now_ms);
+                w.used
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::new()
+    }
+}
+
+/// futures 기본 제한
+fn default_windows() -> Vec<Window> {
+    vec![
+        Window::new(RateLimitKind::RequestWeight, 60_000, 2400),
+        Window::new(RateLimitKind::Orders, 60_000, 1200),
+        Window::new(RateLimitKind::Orders, 10_000, 300),
+    ]
+}
+
+fn cost(kind: RateLimitKind, weight: u32, is_order: bool) -> u32 {
+    match kind {
+        RateLimitKind::RequestWeight => weight,
+        RateLimitKind::Orders => is_order as u32,
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// exchangeInfo의 interval (`SECOND`, `MINUTE`, `HOUR`, `DAY`)
+fn interval_to_ms(interval: &str) -> Option<u64> {
+    match interval {
+        "SECOND" => Some(1_000),
+        "MINUTE" => Some(60_000),
+        "HOUR" => Some(3_600_000),
+        "DAY" => Some(86_400_000),
+        _ => None,
+    }
+}
+
+/// header의 interval (`10s`, `1m`, `1h`, `1d`)
+fn header_interval_to_ms(interval: &str) -> Option<u64> {
+    let unit = interval.chars().last()?;
+    let num: u64 = interval[..interval.len() - 1].parse().ok()?;
+    let unit_ms = match unit {
+        's' => 1_000,
+        'm' => 60_000,
+        'h' => 3_600_000,
+        'd' => 86_400_000,
+        _ => return None,
+    };
+    Some(num * unit_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(rate_limit_type: &str, interval: &str, interval_num: u64, limit: u32) -> RateLimit {
+        RateLimit {
+            rate_limit_type: rate_limit_type.to_string(),
+            interval: interval.to_string(),
+            interval_num,
+            limit,
+        }
+    }
+
+    /// 테스트 도중 window가 바뀌지 않도록 하루 단위 window만 사용한다.
+    fn daily_limiter(weight: u32, orders: u32) -> RateLimiter {
+        let limiter = RateLimiter::new();
+        limiter.configure(&[
+            rate_limit("REQUEST_WEIGHT", "DAY", 1, weight),
+            rate_limit("ORDERS", "DAY", 1, orders),
+        ]);
+        limiter
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.parse().unwrap(), value.parse().unwrap()))
+            .collect()
+    }
+
+    fn windows(limiter: &RateLimiter) -> Vec<(RateLimitKind, u64, u32)> {
+        let state = limiter.state.lock().unwrap();
+        state.windows.iter().map(|w| (w.kind, w.interval_ms, w.limit)).collect()
+    }
+
+    #[test]
+    fn configure_replaces_windows() {
+        let limiter = RateLimiter::new();
+        limiter.configure(&[
+            rate_limit("REQUEST_WEIGHT", "MINUTE", 1, 1200),
+            rate_limit("ORDERS", "SECOND", 10, 100),
+        ]);
+
+        assert_eq!(windows(&limiter), vec![
+            (RateLimitKind::RequestWeight, 60_000, 1200),
+            (RateLimitKind::Orders, 10_000, 100),
+        ]);
+    }
+
+    #[test]
+    fn configure_ignores_zero_interval() {
+        let limiter = RateLimiter::new();
+        limiter.configure(&[
+            rate_limit("REQUEST_WEIGHT", "MINUTE", 0, 1200),
+            rate_limit("ORDERS", "MINUTE", 1, 600),
+        ]);
+
+        assert_eq!(windows(&limiter), vec![
+            (RateLimitKind::Orders, 60_000, 600),
+            (RateLimitKind::RequestWeight, 60_000, 2400),
+        ]);
+        // 0으로 나누지 않는다.
+        assert_eq!(limiter.try_acquire(1, true), None);
+    }
+
+    #[test]
+    fn configure_keeps_default_for_missing_kind() {
+        let limiter = RateLimiter::new();
+        limiter.configure(&[rate_limit("ORDERS", "MINUTE", 1, 600)]);
+
+        assert!(windows(&limiter).contains(&(RateLimitKind::RequestWeight, 60_000, 2400)));
+
+        limiter.configure(&[]);
+        assert_eq!(windows(&limiter), windows(&RateLimiter::new()));
+    }
+
+    #[test]
+    fn refuses_request_over_weight_limit() {
+        let limiter = daily_limiter(10, 100);
+
+        assert_eq!(limiter.try_acquire(6, false), None);
+        let wait = limiter.try_acquire(6, false).unwrap();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(86_400_000));
+        // 거절된 요청은 사용량에 더하지 않는다.
+        assert_eq!(limiter.used_weight(), 6);
+        assert_eq!(limiter.try_acquire(4, false), None);
+        assert_eq!(limiter.used_weight(), 10);
+    }
+
+    #[test]
+    fn sends_oversized_request_into_empty_window() {
+        let limiter = daily_limiter(10, 100);
+
+        assert_eq!(limiter.try_acquire(20, false), None);
+        assert!(limiter.try_acquire(1, false).is_some());
+    }
+
+    #[test]
+    fn counts_orders_separately() {
+        let limiter = daily_limiter(100, 1);
+
+        assert_eq!(limiter.try_acquire(1, true), None);
+        assert!(limiter.try_acquire(1, true).is_some());
+        // 주문이 아닌 요청은 ORDERS 제한과 관계없다.
+        assert_eq!(limiter.try_acquire(1, false), None);
+    }
+
+    #[tokio::test]
+    async fn acquire_waits_while_over_limit() {
+        let limiter = daily_limiter(10, 100);
+        limiter.acquire(10, false).await;
+
+        let acquired = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(1, false)).await;
+
+        assert!(acquired.is_err());
+        assert_eq!(limiter.used_weight(), 10);
+    }
+
+    #[test]
+    fn updates_used_weight_from_headers() {
+        let limiter = daily_limiter(2400, 100);
+        limiter.try_acquire(1, false);
+
+        limiter.update_from_headers(&headers(&[("X-MBX-USED-WEIGHT-1D", "2000")]));
+        assert_eq!(limiter.used_weight(), 2000);
+        assert!(limiter.try_acquire(401, false).is_some());
+        assert_eq!(limiter.try_acquire(400, false), None);
+
+        // 설정에 없는 interval, 숫자가 아닌 값은 무시한다.
+        limiter.update_from_headers(&headers(&[("X-MBX-USED-WEIGHT-1M", "5"), ("X-MBX-USED-WEIGHT-1D", "abc")]));
+        assert_eq!(limiter.used_weight(), 2400);
+    }
+
+    #[test]
+    fn updates_order_count_from_headers() {
+        let limiter = daily_limiter(2400, 300);
+
+        limiter.update_from_headers(&headers(&[("X-MBX-ORDER-COUNT-1D", "300")]));
+
+        assert!(limiter.try_acquire(1, true).is_some());
+        assert_eq!(limiter.try_acquire(1, false), None);
+        assert_eq!(limiter.used_weight(), 1);
+    }
+
+    #[test]
+    fn window_rolls_over_on_wall_clock_boundary() {
+        let minute = 60_000;
+        let mut window = Window::new(RateLimitKind::RequestWeight, minute, 10);
+
+        window.roll(5 * minute + 59_000);
+        window.used = 10;
+        window.roll(5 * minute + 59_999);
+        assert_eq!(window.used, 10);
+        // 첫 요청 후 60초가 아닌 다음 분의 시작까지 기다린다.
+        assert_eq!(window.until_next_window(5 * minute + 59_000), Duration::from_millis(1_000));
+
+        window.roll(6 * minute);
+        assert_eq!(window.used, 0);
+    }
+
+    #[test]
+    fn block_for_refuses_later_requests() {
+        let limiter = RateLimiter::new();
+        limiter.block_for(Duration::from_secs(5));
+        // 더 짧은 block으로 줄어들지 않는다.
+        limiter.block_for(Duration::from_millis(1));
+
+        let wait = limiter.try_acquire(1, false).unwrap();
+        assert!(wait > Duration::from_secs(4) && wait <= Duration::from_secs(5));
+        assert_eq!(limiter.used_weight(), 0);
+    }
+
+    #[tokio::test]
+    async fn acquire_waits_until_block_ends() {
+        let limiter = RateLimiter::new();
+        limiter.block_for(Duration::from_millis(30));
+        let started = Instant::now();
+
+        limiter.acquire(1, false).await;
+
+        assert!(started.elapsed() >= Duration::from_millis(30));
+        assert_eq!(limiter.used_weight(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use crate::Error;
+
+/// 일시적인 실패(5xx, 429, 연결 끊김, -1021)에 대한 재시도 정책
+/// 재시도 간격은 `base_delay * 2^attempt`에 jitter를 더한 값이며 `max_delay`를 넘지 않는다.
+/// 429 응답에 `Retry-After`가 있으면 그 시간만큼 기다린다.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        RetryPolicy { max_retries, base_delay, max_delay }
+    }
+
+    /// 재시도하지 않는 정책
+    pub fn none() -> Self {
+        RetryPolicy::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// 재시도할 수 있는 에러인지
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Transport(e) => e.is_connect() || e.is_timeout() || e.is_request() || e.is_body(),
+            Error::RateLimited { .. } | Error::InvalidTimestamp { .. } => true,
+            Error::Api { status, .. } | Error::Http { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// 요청이 거래소에서 처리되지 않은 것이 확실한 에러인지
+    /// 연결 실패, 429, 418, -1021은 주문이 접수되지 않았으므로 같은 주문을 다시 보내도 된다.
+    /// timeout, 5xx는 주문이 이미 체결됐을 수 있다.
+    pub fn is_never_executed(error: &Error) -> bool {
+        match error {
+            Error::Transport(e) => e.is_connect(),
+            Error::RateLimited { .. } | Error::IpBanned { .. } | Error::InvalidTimestamp { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// `attempt`번째 재시도 전에 기다릴 시간
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        if let Error::RateLimited { retry_after: Some(retry_after), .. } = error {
+            return *retry_after;
+        }
+
+        let exp = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+        let delay = exp.min(self.max_delay);
+        // equal jitter: delay/2 ~ delay 사이의 값
+        delay / 2 + jitter(delay / 2)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// 최대 3번, 200ms부터 최대 5초
+    fn default() -> Self {
+        RetryPolicy::new(3, Duration::from_millis(200), Duration::from_secs(5))
+    }
+}
+
+fn jitter(max: Duration) -> Duration {
+    let max_nanos = max.as_nanos() as u64;
+    if max_nanos == 0 {
+        return Duration::ZERO;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % max_nanos)
+}
@@ -0,0 +1,371 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use crate::model::binance_model::{BinanceSymbol, ExchangeInfo, LotSize, PriceFilter};
+
+/// float 나눗셈 오차 때문에 step의 배수인 값이 한 step 아래로 내려가지 않도록 더하는 값
+const STEP_EPSILON: f64 = 1e-9;
+
+/// 주문을 거래소로 보내기 전에 발견한 symbol filter 위반
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FilterViolation {
+    #[error("unknown symbol: {0}")]
+    UnknownSymbol(String),
+
+    #[error("invalid {field}: {value}")]
+    InvalidNumber { field: &'static str, value: String },
+
+    #[error("price {price} is below min price {min_price}")]
+    PriceTooLow { price: f64, min_price: f64 },
+
+    #[error("price {price} is above max price {max_price}")]
+    PriceTooHigh { price: f64, max_price: f64 },
+
+    /// step size로 내림한 뒤의 수량 기준
+    #[error("quantity {quantity} is below min qty {min_qty}")]
+    QuantityTooSmall { quantity: f64, min_qty: f64 },
+
+    #[error("quantity {quantity} is above max qty {max_qty}")]
+    QuantityTooLarge { quantity: f64, max_qty: f64 },
+
+    #[error("notional {notional} is below min notional {min_notional}")]
+    NotionalTooSmall { notional: f64, min_notional: f64 },
+}
+
+/// tick size, step size에 맞춘 주문 값
+/// 그대로 `NewOrder`의 `price`, `quantity`로 보낼 수 있는 문자열이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedOrder {
+    pub price: Option<String>,
+    pub quantity: Option<String>,
+    pub stop_price: Option<String>,
+}
+
+/// 한 symbol의 주문 제한 (exchangeInfo의 `filters`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub price_filter: Option<PriceFilter>,
+    pub lot_size: Option<LotSize>,
+    pub market_lot_size: Option<LotSize>,
+    pub min_notional: Option<f64>,
+}
+
+impl From<&BinanceSymbol> for SymbolRules {
+    fn from(symbol: &BinanceSymbol) -> Self {
+        SymbolRules {
+            symbol: symbol.symbol.clone(),
+            price_filter: symbol.price_filter().cloned(),
+            lot_size: symbol.lot_size().cloned(),
+            market_lot_size: symbol.market_lot_size().cloned(),
+            min_notional: symbol.min_notional().map(|f| f.notional),
+        }
+    }
+}
+
+impl SymbolRules {
+    /// 가장 가까운 `tickSize`의 배수로 반올림한다.
+    pub fn round_price(&self, price: f64) -> String {
+        match &self.price_filter {
+            Some(filter) if filter.tick_size > 0.0 => {
+                let ticks = (price / filter.tick_size).round();
+                format!("{:.*}", decimals(filter.tick_size), ticks * filter.tick_size)
+            },
+            _ => price.to_string(),
+        }
+    }
+
+    /// `stepSize`의 배수로 내림한다. (주문 수량이 요청한 것보다 커지지 않도록)
+    /// 시장가 주문은 `MARKET_LOT_SIZE`가 있으면 그것을 사용한다.
+    pub fn round_quantity(&self, quantity: f64, is_market: bool) -> String {
+        match self.lot_size_for(is_market) {
+            Some(lot) if lot.step_size > 0.0 => {
+                let steps = (quantity / lot.step_size + STEP_EPSILON).floor();
+                format!("{:.*}", decimals(lot.step_size), steps * lot.step_size)
+            },
+            _ => quantity.to_string(),
+        }
+    }
+
+    /// 가격, 수량을 맞추고 filter를 검사한다.
+    /// - `order_type`이 `MARKET`으로 끝나면 시장가 주문으로 본다.
+    /// - min notional은 `price`, 없으면 `stop_price`, 둘 다 없으면(시장가 주문) `reference_price`(mark price 등)로 검사한다.
+    ///   셋 다 없으면 검사하지 않는다.
+    /// - `PERCENT_PRICE`, `MAX_NUM_ORDERS`는 mark price와 미체결 주문 수가 필요하므로 검사하지 않는다.
+    pub fn normalize_order(
+        &self,
+        order_type: &str,
+        price: Option<f64>,
+        quantity: Option<f64>,
+        stop_price: Option<f64>,
+        reference_price: Option<f64>,
+    ) -> Result<NormalizedOrder, FilterViolation> {
+        let is_market = order_type.ends_with("MARKET");
+
+        let price = price.map(|p| self.round_price(p));
+        let stop_price = stop_price.map(|p| self.round_price(p));
+        let quantity = quantity.map(|q| self.round_quantity(q, is_market));
+
+        // 반올림한 문자열로 검사해야 실제로 보내는 값과 같다.
+        let price_value = price.as_deref().and_then(|p| p.parse::<f64>().ok());
+        let stop_price_value = stop_price.as_deref().and_then(|p| p.parse::<f64>().ok());
+        let quantity_value = quantity.as_deref().and_then(|q| q.parse::<f64>().ok());
+
+        for p in [price_value, stop_price_value].into_iter().flatten() {
+            self.check_price(p)?;
+        }
+        if let Some(q) = quantity_value {
+            self.check_quantity(q, is_market)?;
+
+            if let (Some(p), Some(min_notional)) = (price_value.or(stop_price_value).or(reference_price), self.min_notional) {
+                let notional = p * q;
+                if notional + STEP_EPSILON < min_notional {
+                    return Err(FilterViolation::NotionalTooSmall { notional, min_notional });
+                }
+            }
+        }
+
+        Ok(NormalizedOrder { price, quantity, stop_price })
+    }
+
+    fn check_price(&self, price: f64) -> Result<(), FilterViolation> {
+        let Some(filter) = &self.price_filter else {
+            return Ok(());
+        };
+        // 0이면 제한 없음
+        if filter.min_price > 0.0 && price < filter.min_price {
+            return Err(FilterViolation::PriceTooLow { price, min_price: filter.min_price });
+        }
+        if filter.max_price > 0.0 && price > filter.max_price {
+            return Err(FilterViolation::PriceTooHigh { price, max_price: filter.max_price });
+        }
+        Ok(())
+    }
+
+    fn check_quantity(&self, quantity: f64, is_market: bool) -> Result<(), FilterViolation> {
+        let Some(lot) = self.lot_size_for(is_market) else {
+            return Ok(());
+        };
+        if quantity <= 0.0 || quantity < lot.min_qty {
+            return Err(FilterViolation::QuantityTooSmall { quantity, min_qty: lot.min_qty });
+        }
+        if lot.max_qty > 0.0 && quantity > lot.max_qty {
+            return Err(FilterViolation::QuantityTooLarge { quantity, max_qty: lot.max_qty });
+        }
+        Ok(())
+    }
+
+    fn lot_size_for(&self, is_market: bool) -> Option<&LotSize> {
+        if is_market {
+            self.market_lot_size.as_ref().or(self.lot_size.as_ref())
+        } else {
+            self.lot_size.as_ref()
+        }
+    }
+}
+
+/// 모든 adapter가 공유하는 symbol별 주문 제한
+/// `BinanceCommon::sync_symbol_rules`로 채운다. 비어있으면 주문을 검사하지 않고 그대로 보낸다.
+#[derive(Debug, Default)]
+pub struct SymbolRulesCache {
+    rules: RwLock<HashMap<String, SymbolRules>>,
+}
+
+impl SymbolRulesCache {
+    pub fn new() -> Self {
+        SymbolRulesCache::default()
+    }
+
+    /// 기존 내용은 `exchange_info`로 모두 바뀐다.
+    pub fn update(&self, exchange_info: &ExchangeInfo) {
+        let rules = exchange_info.symbols
+            .iter()
+            .map(|s| (s.symbol.clone(), SymbolRules::from(s)))
+            .collect();
+
+        *self.rules.write().unwrap() = rules;
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<SymbolRules> {
+        self.rules.read().unwrap().get(symbol).cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.read().unwrap().is_empty()
+    }
+
+    /// `SymbolRules::normalize_order`
+    /// 주문 값은 `NewOrder`처럼 문자열로 받는다.
+    /// 캐시가 비어있으면 값을 바꾸지 않고, 캐시에 없는 symbol이면 `UnknownSymbol`을 반환한다.
+    pub fn normalize_order(
+        &self,
+        symbol: &str,
+        order_type: &str,
+        price: Option<String>,
+        quantity: Option<String>,
+        stop_price: Option<String>,
+        reference_price: Option<f64>,
+    ) -> Result<NormalizedOrder, FilterViolation> {
+        if self.is_empty() {
+            return Ok(NormalizedOrder { price, quantity, stop_price });
+        }
+
+        let rules = self.get(symbol).ok_or_else(|| FilterViolation::UnknownSymbol(symbol.to_string()))?;
+        let price = parse_number("price", price)?;
+        let quantity = parse_number("quantity", quantity)?;
+        let stop_price = parse_number("stopPrice", stop_price)?;
+
+        rules.normalize_order(order_type, price, quantity, stop_price, reference_price)
+    }
+
+    /// 가격 없이 보내는 주문(시장가 등)의 min notional을 검사하려면 mark price가 필요한지
+    /// 수량이 없으면 검사할 notional도 없으므로 `false`
+    pub fn needs_reference_price(&self, symbol: &str, price: Option<&str>, quantity: Option<&str>, stop_price: Option<&str>) -> bool {
+        price.is_none()
+            && stop_price.is_none()
+            && quantity.is_some()
+            && self.get(symbol).is_some_and(|r| r.min_notional.is_some())
+    }
+}
+
+fn parse_number(field: &'static str, value: Option<String>) -> Result<Option<f64>, FilterViolation> {
+    value
+        .map(|v| v.trim().parse::<f64>().map_err(|_| FilterViolation::InvalidNumber { field, value: v }))
+        .transpose()
+}
+
+/// `0.001` -> 3, `10` -> 0
+fn decimals(step: f64) -> usize {
+    // f64의 Display는 지수 표기를 쓰지 않는다.
+    step.to_string().split('.').nth(1).map(str::len).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btc() -> SymbolRules {
+        SymbolRules {
+            symbol: "BTCUSDT".to_string(),
+            price_filter: Some(PriceFilter { min_price: 556.8, max_price: 4529764.0, tick_size: 0.1 }),
+            lot_size: Some(LotSize { min_qty: 0.001, max_qty: 1000.0, step_size: 0.001 }),
+            market_lot_size: Some(LotSize { min_qty: 0.001, max_qty: 120.0, step_size: 0.001 }),
+            min_notional: Some(100.0),
+        }
+    }
+
+    fn cache(rules: SymbolRules) -> SymbolRulesCache {
+        let cache = SymbolRulesCache::new();
+        cache.rules.write().unwrap().insert(rules.symbol.clone(), rules);
+        cache
+    }
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn keeps_quantity_already_on_step() {
+        let rules = btc();
+
+        assert_eq!(rules.round_quantity(1.001, false), "1.001");
+        assert_eq!(rules.round_quantity(0.003, false), "0.003");
+        assert_eq!(rules.round_quantity(0.001, true), "0.001");
+    }
+
+    #[test]
+    fn floors_quantity_just_below_step() {
+        let rules = btc();
+
+        assert_eq!(rules.round_quantity(1.0019999, false), "1.001");
+        assert_eq!(rules.round_quantity(0.0009999, false), "0.000");
+        assert_eq!(
+            rules.normalize_order("LIMIT", Some(60000.0), Some(0.0009999), None, None),
+            Err(FilterViolation::QuantityTooSmall { quantity: 0.0, min_qty: 0.001 }),
+        );
+    }
+
+    #[test]
+    fn rounds_price_to_eight_decimal_tick() {
+        let rules = SymbolRules {
+            symbol: "1000PEPEUSDT".to_string(),
+            price_filter: Some(PriceFilter { min_price: 0.0, max_price: 0.0, tick_size: 0.00000001 }),
+            lot_size: None,
+            market_lot_size: None,
+            min_notional: None,
+        };
+
+        assert_eq!(rules.round_price(0.012345678), "0.01234568");
+        assert_eq!(rules.round_price(0.01234567), "0.01234567");
+    }
+
+    #[test]
+    fn rejects_notional_below_minimum() {
+        let rules = btc();
+
+        assert_eq!(
+            rules.normalize_order("LIMIT", Some(60000.0), Some(0.001), None, None),
+            Err(FilterViolation::NotionalTooSmall { notional: 60.0, min_notional: 100.0 }),
+        );
+        // stop price로 검사
+        assert!(matches!(
+            rules.normalize_order("STOP_MARKET", None, Some(0.001), Some(60000.0), None),
+            Err(FilterViolation::NotionalTooSmall { .. }),
+        ));
+        assert_eq!(
+            rules.normalize_order("LIMIT", Some(60000.0), Some(0.002), None, None),
+            Ok(NormalizedOrder { price: some("60000.0"), quantity: some("0.002"), stop_price: None }),
+        );
+    }
+
+    #[test]
+    fn checks_market_notional_with_reference_price() {
+        let rules = btc();
+
+        assert!(matches!(
+            rules.normalize_order("MARKET", None, Some(0.001), None, Some(60000.0)),
+            Err(FilterViolation::NotionalTooSmall { .. }),
+        ));
+        assert!(rules.normalize_order("MARKET", None, Some(0.002), None, Some(60000.0)).is_ok());
+        // 주문 가격이 있으면 reference price는 사용하지 않는다.
+        assert!(rules.normalize_order("LIMIT", Some(60000.0), Some(0.002), None, Some(1.0)).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_symbol() {
+        let cache = cache(btc());
+
+        assert_eq!(
+            cache.normalize_order("ETHUSDT", "LIMIT", some("3000"), some("0.1"), None, None),
+            Err(FilterViolation::UnknownSymbol("ETHUSDT".to_string())),
+        );
+    }
+
+    #[test]
+    fn normalizes_string_values_through_cache() {
+        let cache = cache(btc());
+
+        assert_eq!(
+            cache.normalize_order("BTCUSDT", "STOP", some("60000.04"), some("0.0025"), some("59000.06"), None),
+            Ok(NormalizedOrder { price: some("60000.0"), quantity: some("0.002"), stop_price: some("59000.1") }),
+        );
+        assert_eq!(
+            cache.normalize_order("BTCUSDT", "STOP", some("60000"), some("0.002"), some("abc"), None),
+            Err(FilterViolation::InvalidNumber { field: "stopPrice", value: "abc".to_string() }),
+        );
+        assert!(cache.needs_reference_price("BTCUSDT", None, Some("0.002"), None));
+        assert!(!cache.needs_reference_price("BTCUSDT", None, None, None));
+        assert!(!cache.needs_reference_price("BTCUSDT", Some("60000"), Some("0.002"), None));
+        assert!(!cache.needs_reference_price("BTCUSDT", None, Some("0.002"), Some("60000")));
+    }
+
+    #[test]
+    fn passes_values_through_when_cache_is_empty() {
+        let cache = SymbolRulesCache::new();
+
+        assert_eq!(
+            cache.normalize_order("ANYUSDT", "LIMIT", some("1.23456789"), some("0.0000001"), some("abc"), None),
+            Ok(NormalizedOrder { price: some("1.23456789"), quantity: some("0.0000001"), stop_price: some("abc") }),
+        );
+        assert!(!cache.needs_reference_price("ANYUSDT", None, Some("0.002"), None));
+    }
+}
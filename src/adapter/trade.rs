@@ -1,57 +1,69 @@
-use std::env;
-use anyhow::Ok;
-
 use crate::port::binance_port::TradePort;
-use crate::model::binance_model::{BaseUrl, BinanceRequest, TradeEndpoint};
+use crate::model::binance_model::{CommonEndpoint, PremiumIndex, TradeEndpoint};
 
-use super::adapter_utils;
+use super::{adapter_utils, client::BinanceClient, transport::{ReqwestTransport, Transport}};
 
-pub struct BinanceTrade {
-    secret_key: String,
-    api_key: String,
+pub struct BinanceTrade<R: Transport = ReqwestTransport> {
+    client: BinanceClient<R>,
 }
 
 impl BinanceTrade {
-    pub fn new() -> Self {
-        let api_key = env::var("BINANCE_API_KEY").expect("fail to get api_key");
-        let secret_key = env::var("BINANCE_SECRET_KEY").expect("fail to get secret_key");
-
-        BinanceTrade {
-            secret_key, 
-            api_key, 
-        }
+    /// `BINANCE_API_KEY`, `BINANCE_SECRET_KEY` 환경변수로 client를 만든다.
+    /// 환경변수가 없으면 `Error::Config`를 반환한다.
+    pub fn new() -> crate::Result<Self> {
+        let client = BinanceClient::from_env()?;
+
+        Ok(BinanceTrade { client })
+    }
+
+    /// key를 직접 넘겨서 만든다.
+    pub fn with_credentials(api_key: impl Into<String>, secret_key: impl Into<String>) -> crate::Result<Self> {
+        let client = BinanceClient::builder()
+            .credentials(api_key, secret_key)
+            .build()?;
+
+        Ok(BinanceTrade { client })
     }
+
 }
 
-impl TradePort for BinanceTrade {
-    async fn order_position(&self) -> anyhow::Result<()> {
+impl<R: Transport> BinanceTrade<R> {
+    /// 다른 adapter와 `BinanceClient`를 공유할 때 사용한다.
+    pub fn with_client(client: BinanceClient<R>) -> Self {
+        BinanceTrade { client }
+    }
+
+    pub fn client(&self) -> &BinanceClient<R> {
+        &self.client
+    }
+
+    async fn mark_price(&self, symbol: &str) -> crate::Result<f64> {
+        let res = self.client
+            .execute(CommonEndpoint::PremiumIndex { symbol: Some(symbol.to_string()) })
+            .await?;
+        let index: PremiumIndex = serde_json::from_str(res.as_str())?;
+
+        Ok(index.mark_price)
+    }
+}
+
+impl<R: Transport> TradePort for BinanceTrade<R> {
+    async fn order_position(&self) -> crate::Result<()> {
         Ok(())
     }
     
-    async fn change_leverage(&self, symbol: &str, leverage: i32) -> anyhow::Result<String> {
-        let binance_request = BinanceRequest::new(BaseUrl::future, TradeEndpoint::Leverage { symbol: symbol.to_string(), leverage });
-        
-        let text = adapter_utils::request_with_signature(
-            "post", 
-            &self.secret_key, 
-            &self.api_key, 
-            binance_request
-            )
+    async fn change_leverage(&self, symbol: &str, leverage: i32) -> crate::Result<String> {
+        let text = self.client
+            .execute(TradeEndpoint::Leverage { symbol: symbol.to_string(), leverage })
             .await?;
 
         Ok(text)
     }
     
-    async fn all_open_orders(&self, symbol: &str) -> anyhow::Result<String> {
-        let binance_request = BinanceRequest::new(BaseUrl::future, TradeEndpoint::AllOpenOrder { symbol: symbol.to_string() });
-
-        let text = adapter_utils::request_with_signature(
-            "Delete", 
-            &self.secret_key, 
-            &self.api_key, 
-            binance_request
-        )
-        .await?;
+    async fn all_open_orders(&self, symbol: &str) -> crate::Result<String> {
+        let text = self.client
+            .execute(TradeEndpoint::AllOpenOrder { symbol: symbol.to_string() })
+            .await?;
 
         Ok(text)
     }
@@ -66,25 +78,35 @@ impl TradePort for BinanceTrade {
            price: Option<String>,
            stop_price: Option<f64>,
            callback_rate: Option<f64>,
-       ) -> anyhow::Result<String> {
-        let binance_request = BinanceRequest::new(BaseUrl::future, TradeEndpoint::NewOrder { 
-            symbol: symbol.unwrap_or_default(), 
+       ) -> crate::Result<String> {
+        let symbol = symbol.unwrap_or_default();
+        let r#type = r#type.unwrap_or_default();
+        let stop_price = stop_price.map(|p| p.to_string());
+
+        // 가격 없이 보내는 주문은 mark price로 min notional을 검사한다.
+        let rules = self.client.symbol_rules();
+        let mark_price = if rules.needs_reference_price(&symbol, price.as_deref(), quantity.as_deref(), stop_price.as_deref()) {
+            Some(self.mark_price(&symbol).await?)
+        } else {
+            None
+        };
+
+        // `sync_symbol_rules`를 호출했으면 tick size, step size에 맞추고 filter 위반은 보내기 전에 에러로 반환한다.
+        let order = rules.normalize_order(&symbol, &r#type, price, quantity, stop_price, mark_price)?;
+
+        let endpoint = TradeEndpoint::NewOrder { 
+            symbol, 
             side: side.unwrap_or_default(), 
-            r#type: r#type.unwrap_or_default(), 
-            time_in_force: time_in_force.unwrap_or_default(), 
-            quantity: quantity.unwrap_or_default(), 
-            price: price.unwrap_or_default(), 
-            stop_price: stop_price.unwrap_or_default(), 
-            callback_rate: callback_rate.unwrap_or_default(), 
-        });
-
-        let res = adapter_utils::request_with_signature(
-            "Post", 
-            &self.secret_key, 
-            &self.api_key, 
-            binance_request
-        )
-        .await?;
+            r#type, 
+            time_in_force, 
+            quantity: order.quantity, 
+            price: order.price, 
+            stop_price: order.stop_price, 
+            callback_rate, 
+            new_client_order_id: Some(adapter_utils::create_client_order_id()),
+        };
+
+        let res = self.client.execute(endpoint).await?;
 
         Ok(res)
     }
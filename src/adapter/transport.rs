@@ -0,0 +1,70 @@
+use std::future::Future;
+
+use reqwest::{header::HeaderMap, Method};
+
+/// `Transport`로 보내는 요청
+/// url에는 query(signature 포함)가 모두 들어있다.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: url::Url,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: url::Url) -> Self {
+        HttpRequest { method, url, headers: HeaderMap::new(), body: None }
+    }
+}
+
+/// `Transport`가 돌려주는 응답
+/// 바이낸스 에러 변환은 status와 body로 adapter에서 한다.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// HTTP 요청을 실제로 보내는 부분
+/// adapter는 이 trait에만 의존하므로 `MockTransport` 등으로 바꿔 네트워크 없이 테스트할 수 있다.
+/// 연결 실패 같은 전송 에러만 `Err`로 반환하고, 4xx, 5xx 응답은 `Ok`로 반환한다.
+pub trait Transport: Send + Sync + 'static {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = crate::Result<HttpResponse>> + Send;
+}
+
+/// `reqwest::Client`를 사용하는 기본 transport
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+        let mut builder = self.client
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        // reqwest 에러 메시지에는 signature가 포함된 url이 들어있다.
+        let response = builder.send().await.map_err(|e| e.without_url())?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.map_err(|e| e.without_url())?;
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
@@ -1,116 +1,67 @@
-use anyhow::{Context, Ok};
-use reqwest::Client;
+use super::client::BinanceClient;
+use super::transport::{ReqwestTransport, Transport};
+use crate::{model::binance_model::UserEndpoint, port::binance_port::UserPort};
 
-use super::adapter_utils::{self, create_signature, create_timestamp};
-use crate::{model::binance_model::{BaseUrl, BinanceRequest, Endpoint, UserEndpoint}, port::binance_port::UserPort};
-use std::env;
-
-pub struct BinanceUser {
-    secret_key: String,
-    api_key: String,
+pub struct BinanceUser<R: Transport = ReqwestTransport> {
+    client: BinanceClient<R>,
 }
 
 impl BinanceUser {
-    pub fn new() -> Self {
-        let api_key = env::var("BINANCE_API_KEY").expect("fail to get api_key");
-        let secret_key = env::var("BINANCE_SECRET_KEY").expect("fail to get secret_key");
+    /// `BINANCE_API_KEY`, `BINANCE_SECRET_KEY` 환경변수로 client를 만든다.
+    /// 환경변수가 없으면 `Error::Config`를 반환한다.
+    pub fn new() -> crate::Result<Self> {
+        let client = BinanceClient::from_env()?;
 
-        BinanceUser {
-            secret_key, 
-            api_key, 
-        }
+        Ok(BinanceUser { client })
     }
 
-    pub async fn get<T>(&self, binacne_request: BinanceRequest<T>) -> anyhow::Result<String> 
-    where 
-        T: Endpoint + Into<String>
-    {
-        let res = adapter_utils::request_with_signature(
-            "get", 
-            &self.secret_key, 
-            &self.api_key, 
-            binacne_request
-        ).await?;
+    /// key를 직접 넘겨서 만든다.
+    pub fn with_credentials(api_key: impl Into<String>, secret_key: impl Into<String>) -> crate::Result<Self> {
+        let client = BinanceClient::builder()
+            .credentials(api_key, secret_key)
+            .build()?;
 
-        Ok(res)
+        Ok(BinanceUser { client })
     }
 
-    pub async fn post<T>(&self, binacne_request: BinanceRequest<T>) -> anyhow::Result<String> 
-    where 
-        T: Endpoint + Into<String>
-    {
-        let res = adapter_utils::request_with_signature(
-            "post", 
-            &self.secret_key, 
-            &self.api_key, 
-            binacne_request
-        ).await?;
+}
 
-        Ok(res)
+impl<R: Transport> BinanceUser<R> {
+    /// 다른 adapter와 `BinanceClient`를 공유할 때 사용한다.
+    pub fn with_client(client: BinanceClient<R>) -> Self {
+        BinanceUser { client }
     }
 
+    pub fn client(&self) -> &BinanceClient<R> {
+        &self.client
+    }
 }
 
-impl UserPort for BinanceUser {
-    async fn get_account_balance(&self) -> anyhow::Result<String> {
-        let client = Client::new();
-        let base_url = "https://fapi.binance.com";  
-
-        // 타임스템프 생성 (바이낸스는 밀리초 단위 요구)
-        let timestamp = create_timestamp()?;
-        let query = format!("timestamp={}", timestamp);
-
-        let signature = create_signature(&self.secret_key, &query)?;
-
-        let url = format!("{}/fapi/v3/balance?{}&signature={}", base_url, query, signature);
+impl<R: Transport> UserPort for BinanceUser<R> {
+    async fn get_account_balance(&self) -> crate::Result<String> {
+        let res = self.client.execute(UserEndpoint::Balance).await?;
 
-        // API 요청 보내기
-        let response = client
-            .get(&url)
-            .header("X-MBX-APIKEY", self.api_key.clone())
-            .send()
-            .await
-            .context("fail to send")?
-            .text()
-            .await?;
-
-        Ok(response)
+        Ok(res)
     }
     
-    async fn get_trade_fee(&self) -> anyhow::Result<String> {
+    async fn get_trade_fee(&self) -> crate::Result<String> {
         Ok("".to_string())
     }
 
     // 차라리 request만 만들어서 매개변수로 넘겨주면 될 것 같은데?
     
-    async fn query_order(&self, symbol: &str) -> anyhow::Result<String> {
-        let binance_request = BinanceRequest::new(
-            BaseUrl::future, 
-            UserEndpoint::QueryOrder { symbol: symbol.to_string() },
-        );
-
-        let res = adapter_utils::request_with_signature(
-            "get", 
-            &self.secret_key, 
-            &self.api_key, 
-            binance_request
-        ).await?;
+    async fn query_order(&self, symbol: &str) -> crate::Result<String> {
+        let res = self.client
+            .execute(UserEndpoint::QueryOrder { symbol: symbol.to_string(), orig_client_order_id: None })
+            .await?;
 
         Ok(res) 
     }
     
-    async fn all_orders(&self, symbol: &str) -> anyhow::Result<String> {
-        let binance_request = BinanceRequest::new(
-            BaseUrl::future, 
-            UserEndpoint::AllOrders { symbol: symbol.to_string() },
-        );
-
-        let res = adapter_utils::request_with_signature(
-            "get", 
-            &self.secret_key, 
-            &self.api_key, 
-            binance_request
-        ).await?;
+    async fn all_orders(&self, symbol: &str) -> crate::Result<String> {
+        let res = self.client
+            .execute(UserEndpoint::AllOrders { symbol: symbol.to_string() })
+            .await?;
 
         Ok(res) 
     }
@@ -1,8 +1,6 @@
 use core::fmt;
 
-use anyhow::Ok;
-
-use crate::{model::binance_model::{Kline, Klines}, utils};
+use crate::{model::binance_model::Klines, utils};
 
 /// K = 2 / ( N  + 1 )
 /// EMAt : 현재 EMA 값
@@ -26,7 +24,7 @@ pub fn ema(prices : &[f64], period : usize) -> Vec<f64> {
 
     ema_values.remove(0);
 
-    return ema_values;
+    ema_values
 }
 
 #[derive(Clone, serde::Serialize, Default)]
@@ -56,8 +54,8 @@ impl EMA {
 
 impl fmt::Debug for EMA {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let open_time = utils::timestamp_to_local(self.open_time as i64).map_err(|e| std::fmt::Error)?;
-        let close_time = utils::timestamp_to_local(self.close_time as i64).map_err(|e| std::fmt::Error)?;
+        let open_time = utils::timestamp_to_local(self.open_time as i64).map_err(|_| std::fmt::Error)?;
+        let close_time = utils::timestamp_to_local(self.close_time as i64).map_err(|_| std::fmt::Error)?;
         write!(f, "EMA: {{\n  open_time: {},\n  close_time: {},\n  period: {},\n  ema_data: {}\n}}", open_time, close_time, self.period, self.ema_data)
     }
 }
@@ -80,7 +78,7 @@ pub fn ema_kline(prices : Klines, period : usize) -> Vec<EMA> {
 
     ema_values.remove(0);
 
-    return ema_values;
+    ema_values
 }
 
 pub fn calculate_disparity(ema: f64, price: f64) -> f64 {
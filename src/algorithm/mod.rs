@@ -1 +1,2 @@
+#[allow(clippy::module_inception)]
 pub mod algorithm;
\ No newline at end of file
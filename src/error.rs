@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::adapter::symbol_rules::FilterViolation;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// crate 전체에서 사용하는 에러
+/// 바이낸스가 돌려준 `{code, msg}` 에러는 알려진 code별로 variant가 나뉘고,
+/// 전송(transport) 에러와 응답 파싱 에러는 따로 구분된다.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// -2018, -2019
+    #[error("insufficient margin ({code}): {msg}")]
+    InsufficientMargin { code: i64, msg: String },
+
+    /// -1021 timestamp가 recvWindow 밖에 있음
+    #[error("invalid timestamp ({code}): {msg}")]
+    InvalidTimestamp { code: i64, msg: String },
+
+    /// -2011, -2013
+    #[error("unknown order ({code}): {msg}")]
+    UnknownOrder { code: i64, msg: String },
+
+    /// HTTP 429 또는 -1003, -1015
+    #[error("rate limited (status {status}): {msg}")]
+    RateLimited { status: u16, code: Option<i64>, msg: String, retry_after: Option<Duration> },
+
+    /// HTTP 418
+    #[error("ip banned: {msg}")]
+    IpBanned { msg: String, retry_after: Option<Duration> },
+
+    /// symbol filter(tickSize, stepSize, minNotional 등) 위반
+    #[error("filter failure ({code}): {msg}")]
+    FilterFailure { code: i64, msg: String },
+
+    /// 거래소로 보내기 전에 symbol filter 위반을 발견한 경우 (`SymbolRulesCache`)
+    #[error("order rejected before sending: {0}")]
+    OrderRejected(#[from] FilterViolation),
+
+    /// 그 외 바이낸스 에러
+    #[error("binance error ({code}, status {status}): {msg}")]
+    Api { status: u16, code: i64, msg: String },
+
+    /// `{code, msg}` 형태가 아닌 에러 응답
+    #[error("http error (status {status}): {body}")]
+    Http { status: u16, body: String },
+
+    #[error("transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    #[error("fail to parse json: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// json은 맞지만 예상한 구조가 아닌 경우
+    #[error("fail to decode response: {0}")]
+    Decode(String),
+
+    #[error("invalid url: {0}")]
+    Url(#[from] url::ParseError),
+
+    /// 잘못된 설정 (key 누락, header 값 오류 등)
+    #[error("config error: {0}")]
+    Config(String),
+}
+
+#[derive(Deserialize)]
+struct BinanceErrorBody {
+    code: i64,
+    msg: String,
+}
+
+impl Error {
+    /// HTTP status와 body를 보고 에러로 변환한다.
+    /// 성공 응답이면 `None`을 반환한다.
+    pub fn from_response(status: u16, body: &str, retry_after: Option<Duration>) -> Option<Error> {
+        let payload = serde_json::from_str::<BinanceErrorBody>(body).ok();
+        let is_success = (200..300).contains(&status);
+
+        // 성공 응답에도 `{"code":200,"msg":"success"}` 같은 body가 오기 때문에 음수 code만 에러로 본다.
+        if is_success && payload.as_ref().is_none_or(|p| p.code >= 0) {
+            return None;
+        }
+
+        if status == 418 {
+            let msg = payload.map(|p| p.msg).unwrap_or_else(|| body.to_string());
+            return Some(Error::IpBanned { msg, retry_after });
+        }
+
+        let Some(BinanceErrorBody { code, msg }) = payload else {
+            if status == 429 {
+                return Some(Error::RateLimited { status, code: None, msg: body.to_string(), retry_after });
+            }
+            return Some(Error::Http { status, body: body.to_string() });
+        };
+
+        let error = match code {
+            -2018 | -2019 => Error::InsufficientMargin { code, msg },
+            -1021 => Error::InvalidTimestamp { code, msg },
+            -2011 | -2013 => Error::UnknownOrder { code, msg },
+            -1003 | -1015 => Error::RateLimited { status, code: Some(code), msg, retry_after },
+            _ if status == 429 => Error::RateLimited { status, code: Some(code), msg, retry_after },
+            -1013 | -1111 | -4003 | -4004 | -4005 | -4014 | -4023 | -4024 | -4164 => Error::FilterFailure { code, msg },
+            _ => Error::Api { status, code, msg },
+        };
+
+        Some(error)
+    }
+
+    /// 바이낸스 에러 code (있는 경우)
+    pub fn code(&self) -> Option<i64> {
+        match self {
+            Error::InsufficientMargin { code, .. }
+            | Error::InvalidTimestamp { code, .. }
+            | Error::UnknownOrder { code, .. }
+            | Error::FilterFailure { code, .. }
+            | Error::Api { code, .. } => Some(*code),
+            Error::RateLimited { code, .. } => *code,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(error: &Error) -> &'static str {
+        match error {
+            Error::InsufficientMargin { .. } => "InsufficientMargin",
+            Error::InvalidTimestamp { .. } => "InvalidTimestamp",
+            Error::UnknownOrder { .. } => "UnknownOrder",
+            Error::RateLimited { .. } => "RateLimited",
+            Error::IpBanned { .. } => "IpBanned",
+            Error::FilterFailure { .. } => "FilterFailure",
+            Error::Api { .. } => "Api",
+            Error::Http { .. } => "Http",
+            _ => "other",
+        }
+    }
+
+    #[test]
+    fn maps_responses_to_variants() {
+        let cases = [
+            (400, r#"{"code":-2019,"msg":"Margin is insufficient."}"#, Some("InsufficientMargin"), Some(-2019)),
+            (400, r#"{"code":-2018,"msg":"Balance is insufficient."}"#, Some("InsufficientMargin"), Some(-2018)),
+            (400, r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#, Some("InvalidTimestamp"), Some(-1021)),
+            (400, r#"{"code":-2013,"msg":"Order does not exist."}"#, Some("UnknownOrder"), Some(-2013)),
+            (400, r#"{"code":-2011,"msg":"Unknown order sent."}"#, Some("UnknownOrder"), Some(-2011)),
+            (429, r#"{"code":-1003,"msg":"Too many requests."}"#, Some("RateLimited"), Some(-1003)),
+            (400, r#"{"code":-1015,"msg":"Too many new orders."}"#, Some("RateLimited"), Some(-1015)),
+            (429, r#"{"code":-1000,"msg":"unknown"}"#, Some("RateLimited"), Some(-1000)),
+            (429, "Too Many Requests", Some("RateLimited"), None),
+            (418, r#"{"code":-1003,"msg":"Way too many requests; IP banned."}"#, Some("IpBanned"), None),
+            (418, "I'm a teapot", Some("IpBanned"), None),
+            (400, r#"{"code":-1013,"msg":"Filter failure: LOT_SIZE"}"#, Some("FilterFailure"), Some(-1013)),
+            (400, r#"{"code":-4164,"msg":"Order's notional must be no smaller than 100"}"#, Some("FilterFailure"), Some(-4164)),
+            (400, r#"{"code":-1121,"msg":"Invalid symbol."}"#, Some("Api"), Some(-1121)),
+            (200, r#"{"code":-1000,"msg":"unknown"}"#, Some("Api"), Some(-1000)),
+            (502, "<html>Bad Gateway</html>", Some("Http"), None),
+            (200, "[]", None, None),
+            (200, r#"{"code":200,"msg":"success"}"#, None, None),
+        ];
+
+        for (status, body, expected, code) in cases {
+            let error = Error::from_response(status, body, None);
+
+            assert_eq!(error.as_ref().map(variant), expected, "status {status}, body {body}");
+            assert_eq!(error.as_ref().and_then(Error::code), code, "status {status}, body {body}");
+        }
+    }
+
+    #[test]
+    fn keeps_retry_after() {
+        let retry_after = Some(Duration::from_secs(7));
+
+        let error = Error::from_response(429, r#"{"code":-1003,"msg":"Too many requests."}"#, retry_after);
+        assert!(matches!(error, Some(Error::RateLimited { status: 429, retry_after: Some(d), .. }) if d == Duration::from_secs(7)));
+
+        let error = Error::from_response(418, r#"{"code":-1003,"msg":"banned"}"#, retry_after);
+        assert!(matches!(error, Some(Error::IpBanned { msg, retry_after: Some(_) }) if msg == "banned"));
+    }
+}
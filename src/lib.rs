@@ -4,4 +4,7 @@ pub mod model;
 pub mod macros;
 pub mod utils;
 pub mod port;
-pub mod algorithm;
\ No newline at end of file
+pub mod algorithm;
+pub mod error;
+
+pub use error::{Error, Result};
\ No newline at end of file
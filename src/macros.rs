@@ -1,18 +1,134 @@
+use serde::Serialize;
+
+/// endpoint 필드로 query string을 만든다.
+/// - 변수 이름은 camelCase로 바꿔서 사용한다. (`time_in_force` -> `timeInForce`, `r#type` -> `type`)
+/// - 값은 percent-encoding 된다.
+/// - `None`인 필드는 빠진다.
+/// - `Vec`은 `symbols=["BTCUSDT","ETHUSDT"]`, `serde_json::Value`는 json 문자열이 된다. (`batchOrders` 등)
+/// - 순서는 인자 순서 그대로이므로 같은 값이면 항상 같은 query(서명)가 나온다.
+/// # Example
+/// ```
+/// use crypto_trading::query;
+///
+/// let symbol = "BTCUSDT";
+/// let time_in_force: Option<&str> = None;
+/// let stop_price: Option<f64> = Some(0.5);
+/// let symbols = vec!["BTCUSDT", "ETHUSDT"];
+/// assert_eq!(
+///     query!(symbol, time_in_force, stop_price, symbols),
+///     "symbol=BTCUSDT&stopPrice=0.5&symbols=%5B%22BTCUSDT%22%2C%22ETHUSDT%22%5D"
+/// );
+/// ```
 #[macro_export]
 macro_rules! query {
-    ($($field:expr), *) => {{
-        let mut query = String::new();
+    ($($field:ident), *) => {{
+        #[allow(unused_mut)]
+        let mut query = $crate::macros::QueryBuilder::new();
         $(
-            if !query.is_empty() {
-                query.push('&');
+            query.push(stringify!($field), &$field);
+        )*
+        query.build()
+    }
+    };
+}
+
+/// `query!`에서 사용하는 query string builder
+#[derive(Debug, Default)]
+pub struct QueryBuilder {
+    query: String,
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        QueryBuilder::default()
+    }
+
+    /// `name`은 camelCase로 바뀐다. 값이 `None`이면 추가하지 않는다.
+    pub fn push<V: QueryValue + ?Sized>(&mut self, name: &str, value: &V) -> &mut Self {
+        if let Some(value) = value.query_value() {
+            if !self.query.is_empty() {
+                self.query.push('&');
             }
-            let mut name = stringify!($field).to_string();
-            if name.contains("r#") {
-                name = name.replace("r#", "");
+            self.query.push_str(&to_camel_case(name));
+            self.query.push('=');
+            self.query.extend(url::form_urlencoded::byte_serialize(value.as_bytes()));
+        }
+        self
+    }
+
+    pub fn build(self) -> String {
+        self.query
+    }
+}
+
+/// query 파라미터 값으로 쓸 수 있는 타입
+/// `None`을 반환하면 파라미터를 빼고 보낸다.
+pub trait QueryValue {
+    fn query_value(&self) -> Option<String>;
+}
+
+macro_rules! impl_query_value {
+    ($($t:ty), *) => {
+        $(
+            impl QueryValue for $t {
+                fn query_value(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
             }
-            query.push_str(&format!("{}={}", name, $field));
         )*
-        query
-    }
     };
-}
\ No newline at end of file
+}
+
+impl_query_value!(str, String, bool, i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+impl<T: QueryValue + ?Sized> QueryValue for &T {
+    fn query_value(&self) -> Option<String> {
+        (**self).query_value()
+    }
+}
+
+impl<T: QueryValue> QueryValue for Option<T> {
+    fn query_value(&self) -> Option<String> {
+        self.as_ref().and_then(|v| v.query_value())
+    }
+}
+
+impl<T: Serialize> QueryValue for Vec<T> {
+    fn query_value(&self) -> Option<String> {
+        serde_json::to_string(self).ok()
+    }
+}
+
+impl<T: Serialize> QueryValue for [T] {
+    fn query_value(&self) -> Option<String> {
+        serde_json::to_string(self).ok()
+    }
+}
+
+impl QueryValue for serde_json::Value {
+    fn query_value(&self) -> Option<String> {
+        match self {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+}
+
+/// `time_in_force` -> `timeInForce`, `r#type` -> `type`
+fn to_camel_case(name: &str) -> String {
+    let name = name.trim_start_matches("r#");
+    let mut camel = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
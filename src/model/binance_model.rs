@@ -1,11 +1,11 @@
 use core::fmt;
-use std::{default, fmt::{write, Display}, time::{SystemTime, UNIX_EPOCH}};
 
-use anyhow::{Context, Ok};
+use anyhow::Ok;
 use serde::{Deserialize, Serialize};
 
-use crate::{query, utils};
+use crate::{macros::QueryValue, query, utils};
 
+#[allow(dead_code)]
 #[derive(Deserialize, Debug, Clone)]
 struct Balance {
     #[serde(rename = "accountAlias")]
@@ -32,7 +32,7 @@ struct Balance {
 #[derive(Serialize,Deserialize, Default, Clone)]
 pub struct Kline {
     pub symbol: String,
-    pub interval: String,
+    pub interval: KlineInterval,
     pub open_time: u64,  // 이 필드는 i64로 수정합니다.
     pub open: f64,
     pub high: f64,
@@ -40,6 +40,12 @@ pub struct Kline {
     pub close: f64,
     pub volume: f64,
     pub close_time: u64,
+    pub quote_volume: f64,
+    pub trade_count: u64,
+    /// taker가 매수한 base asset 수량
+    pub taker_buy_base_volume: f64,
+    /// taker가 매수한 quote asset 수량
+    pub taker_buy_quote_volume: f64,
     pub idx: u64,
 }
 
@@ -71,6 +77,15 @@ impl Kline {
         }
     }
 
+    // 전체 거래량 중 taker 매수 비율 (0.0 ~ 1.0)
+    // 거래량이 0이면 None
+    pub fn taker_buy_ratio(&self) -> Option<f64> {
+        if self.volume == 0.0 {
+            return None;
+        }
+        Some(self.taker_buy_base_volume / self.volume)
+    }
+
     // 양봉인지 체크
     pub fn is_green_candle(&self) -> bool {
         // 시가보다 종가가 높으면 양봉
@@ -87,12 +102,184 @@ impl fmt::Debug for Kline {
         let open_str = utils::timestamp_to_local(self.open_time as i64).map_err(|_| std::fmt::Error)?;
         let close_str = utils::timestamp_to_local(self.close_time as i64).map_err(|_| std::fmt::Error)?;
         write!(f, 
-            "kline {{\n  open_time: {},\n  open: {},\n  high: {},\n  low: {},\n  close: {},\n  volume: {},\n  close_time: {}\n}}",  
-            open_str, self.open, self.high, self.low, self.close, self.volume, close_str
+            "kline {{\n  open_time: {},\n  open: {},\n  high: {},\n  low: {},\n  close: {},\n  volume: {},\n  close_time: {},\n  quote_volume: {},\n  trade_count: {},\n  taker_buy_base_volume: {},\n  taker_buy_quote_volume: {}\n}}",  
+            open_str, self.open, self.high, self.low, self.close, self.volume, close_str,
+            self.quote_volume, self.trade_count, self.taker_buy_base_volume, self.taker_buy_quote_volume
         )
     }
 }
 
+const MINUTE_MS: u64 = 60 * 1000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+const DAY_MS: u64 = 24 * HOUR_MS;
+/// 1970-01-01은 목요일이므로 첫 월요일(주봉 시작)은 4일 뒤이다.
+const FIRST_MONDAY_MS: u64 = 4 * DAY_MS;
+
+/// 바이낸스 kline interval
+/// 시간 계산은 모두 UTC, ms 단위이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum KlineInterval {
+    #[default]
+    Min1,
+    Min3,
+    Min5,
+    Min15,
+    Min30,
+    Hour1,
+    Hour2,
+    Hour4,
+    Hour6,
+    Hour8,
+    Hour12,
+    Day1,
+    Day3,
+    Week1,
+    Month1,
+}
+
+impl KlineInterval {
+    pub const ALL: [KlineInterval; 15] = [
+        KlineInterval::Min1, KlineInterval::Min3, KlineInterval::Min5, KlineInterval::Min15, KlineInterval::Min30,
+        KlineInterval::Hour1, KlineInterval::Hour2, KlineInterval::Hour4, KlineInterval::Hour6, KlineInterval::Hour8, KlineInterval::Hour12,
+        KlineInterval::Day1, KlineInterval::Day3, KlineInterval::Week1, KlineInterval::Month1,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KlineInterval::Min1 => "1m",
+            KlineInterval::Min3 => "3m",
+            KlineInterval::Min5 => "5m",
+            KlineInterval::Min15 => "15m",
+            KlineInterval::Min30 => "30m",
+            KlineInterval::Hour1 => "1h",
+            KlineInterval::Hour2 => "2h",
+            KlineInterval::Hour4 => "4h",
+            KlineInterval::Hour6 => "6h",
+            KlineInterval::Hour8 => "8h",
+            KlineInterval::Hour12 => "12h",
+            KlineInterval::Day1 => "1d",
+            KlineInterval::Day3 => "3d",
+            KlineInterval::Week1 => "1w",
+            KlineInterval::Month1 => "1M",
+        }
+    }
+
+    /// 한 캔들의 길이
+    /// `Month1`은 달마다 길이가 다르므로 30일로 계산한다. 정확한 시간은 `close_time`, `next_open_time`을 사용한다.
+    pub fn duration(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.fixed_ms().unwrap_or(30 * DAY_MS))
+    }
+
+    /// `Month1`을 제외한 캔들 길이 (ms)
+    fn fixed_ms(&self) -> Option<u64> {
+        let ms = match self {
+            KlineInterval::Min1 => MINUTE_MS,
+            KlineInterval::Min3 => 3 * MINUTE_MS,
+            KlineInterval::Min5 => 5 * MINUTE_MS,
+            KlineInterval::Min15 => 15 * MINUTE_MS,
+            KlineInterval::Min30 => 30 * MINUTE_MS,
+            KlineInterval::Hour1 => HOUR_MS,
+            KlineInterval::Hour2 => 2 * HOUR_MS,
+            KlineInterval::Hour4 => 4 * HOUR_MS,
+            KlineInterval::Hour6 => 6 * HOUR_MS,
+            KlineInterval::Hour8 => 8 * HOUR_MS,
+            KlineInterval::Hour12 => 12 * HOUR_MS,
+            KlineInterval::Day1 => DAY_MS,
+            KlineInterval::Day3 => 3 * DAY_MS,
+            KlineInterval::Week1 => 7 * DAY_MS,
+            KlineInterval::Month1 => return None,
+        };
+        Some(ms)
+    }
+
+    /// `timestamp`가 속한 캔들의 open time
+    /// 주봉은 월요일 00:00, 월봉은 매월 1일 00:00에 시작한다.
+    pub fn align(&self, timestamp: u64) -> u64 {
+        match (self, self.fixed_ms()) {
+            (KlineInterval::Week1, Some(ms)) => {
+                // 첫 월요일 이전은 0으로 본다.
+                match timestamp.checked_sub(FIRST_MONDAY_MS) {
+                    Some(t) => t / ms * ms + FIRST_MONDAY_MS,
+                    None => 0,
+                }
+            },
+            (_, Some(ms)) => timestamp / ms * ms,
+            (_, None) => {
+                let date = month_start(timestamp);
+                date.and_utc().timestamp_millis().max(0) as u64
+            },
+        }
+    }
+
+    /// `timestamp`가 속한 캔들 다음 캔들의 open time
+    pub fn next_open_time(&self, timestamp: u64) -> u64 {
+        match self.fixed_ms() {
+            Some(ms) => self.align(timestamp) + ms,
+            None => {
+                let start = month_start(timestamp);
+                let next = start
+                    .checked_add_months(chrono::Months::new(1))
+                    .unwrap_or(start);
+                next.and_utc().timestamp_millis().max(0) as u64
+            },
+        }
+    }
+
+    /// `open_time`에 시작한 캔들의 close time (바이낸스와 같이 다음 open time - 1ms)
+    pub fn close_time(&self, open_time: u64) -> u64 {
+        self.next_open_time(open_time) - 1
+    }
+}
+
+/// `timestamp`(ms)가 속한 달의 1일 00:00 (UTC)
+fn month_start(timestamp: u64) -> chrono::NaiveDateTime {
+    use chrono::Datelike;
+
+    let date = chrono::DateTime::from_timestamp_millis(timestamp as i64)
+        .unwrap_or_default()
+        .date_naive();
+    date.with_day(1).unwrap_or(date).and_hms_opt(0, 0, 0).unwrap_or_default()
+}
+
+impl fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for KlineInterval {
+    type Err = crate::Error;
+
+    /// `1m`과 `1M`(월봉)은 대소문자로 구분한다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KlineInterval::ALL
+            .into_iter()
+            .find(|i| i.as_str() == s)
+            .ok_or_else(|| crate::Error::Config(format!("unknown kline interval: {}", s)))
+    }
+}
+
+impl TryFrom<String> for KlineInterval {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<KlineInterval> for String {
+    fn from(value: KlineInterval) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl QueryValue for KlineInterval {
+    fn query_value(&self) -> Option<String> {
+        Some(self.as_str().to_string())
+    }
+}
+
 #[derive(Deserialize, Default, Clone)]
 pub struct Klines {
     pub kline_list: Vec<Kline>,
@@ -141,6 +328,8 @@ where
 {
     pub base_url: BaseUrl,
     pub endpoint_url: T,
+    /// 요청별 recvWindow (ms). 없으면 client 설정을 사용한다.
+    pub recv_window: Option<u64>,
 }
 
 impl<T> BinanceRequest<T> 
@@ -151,74 +340,240 @@ where
         BinanceRequest {
             base_url,
             endpoint_url,
+            recv_window: None,
         }
     }
 
+    pub fn with_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
     pub fn base_url(&self) -> String {
-        self.base_url.into()
+        self.base_url.url()
     }
 
     pub fn query(&self) -> String {
         self.endpoint_url.query()
     }
 
-    pub fn query_with_timestamp(&self) -> String {
-        self.endpoint_url.query_with_tiemstamp()
+    pub fn method(&self) -> HttpMethod {
+        self.endpoint_url.method()
+    }
+
+    pub fn security_type(&self) -> SecurityType {
+        self.endpoint_url.security_type()
+    }
+
+    pub fn weight(&self) -> u32 {
+        self.endpoint_url.weight()
+    }
+
+    pub fn is_order(&self) -> bool {
+        self.endpoint_url.is_order()
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// REST api 서버 주소
+/// `Custom`에는 로컬 mock 서버 등의 주소를 넣는다. (예: `http://127.0.0.1:8080`)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum BaseUrl {
-    future, 
-    spot,
+    #[default]
+    Future,
+    Spot,
+    FutureTestnet,
+    SpotTestnet,
+    Custom(String),
+}
+
+impl BaseUrl {
+    pub fn url(&self) -> String {
+        match self {
+            BaseUrl::Future => "https://fapi.binance.com".to_string(),
+            BaseUrl::Spot => "https://api.binance.com".to_string(),
+            BaseUrl::FutureTestnet => "https://testnet.binancefuture.com".to_string(),
+            BaseUrl::SpotTestnet => "https://testnet.binance.vision".to_string(),
+            BaseUrl::Custom(url) => url.clone(),
+        }
+    }
+
+    /// 같은 서버의 WebSocket 주소
+    /// `Custom`은 알 수 없으므로 http(s)를 ws(s)로 바꾼 주소를 사용한다.
+    pub fn ws(&self) -> WsBaseUrl {
+        match self {
+            BaseUrl::Future => WsBaseUrl::Future,
+            BaseUrl::Spot => WsBaseUrl::Spot,
+            BaseUrl::FutureTestnet => WsBaseUrl::FutureTestnet,
+            BaseUrl::SpotTestnet => WsBaseUrl::SpotTestnet,
+            BaseUrl::Custom(url) => WsBaseUrl::Custom(url.replacen("http", "ws", 1)),
+        }
+    }
 }
 
 impl From<BaseUrl> for String {
     fn from(value: BaseUrl) -> Self {
-        match value {
-            BaseUrl::future => "https://fapi.binance.com".to_string(),
-            BaseUrl::spot => "https://api.binance.com".to_string(),
+        value.url()
+    }
+}
+
+/// WebSocket stream 서버 주소
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum WsBaseUrl {
+    #[default]
+    Future,
+    Spot,
+    FutureTestnet,
+    SpotTestnet,
+    Custom(String),
+}
+
+impl WsBaseUrl {
+    pub fn url(&self) -> String {
+        match self {
+            WsBaseUrl::Future => "wss://fstream.binance.com".to_string(),
+            WsBaseUrl::Spot => "wss://stream.binance.com:9443".to_string(),
+            WsBaseUrl::FutureTestnet => "wss://stream.binancefuture.com".to_string(),
+            WsBaseUrl::SpotTestnet => "wss://stream.testnet.binance.vision".to_string(),
+            WsBaseUrl::Custom(url) => url.clone(),
         }
     }
 }
 
+impl From<WsBaseUrl> for String {
+    fn from(value: WsBaseUrl) -> Self {
+        value.url()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum CommonEndpoint {
+    /// `limit`: 최대 1500 (기본값 500)
     Klines {
         symbol: String,
-        interval: String,
+        interval: KlineInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
         limit: Option<i32>, 
     }, 
-    OrderBook,
-    RecentTradesList,
-    HistoricalTrades,
+    /// `limit`: 5, 10, 20, 50, 100, 500, 1000 (기본값 500)
+    OrderBook {
+        symbol: String,
+        limit: Option<u32>,
+    },
+    /// `limit`: 최대 1000 (기본값 500)
+    RecentTradesList {
+        symbol: String,
+        limit: Option<u32>,
+    },
+    /// api key header가 필요하다.
+    HistoricalTrades {
+        symbol: String,
+        limit: Option<u32>,
+        from_id: Option<u64>,
+    },
+    /// `start_time`, `end_time`을 같이 보낼 때는 1시간 이내여야 한다.
+    AggTrades {
+        symbol: String,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    },
     ExchnageInfo,
-    Ticker,
+    /// symbol이 없으면 전체 symbol
+    Ticker {
+        symbol: Option<String>,
+    },
+    ServerTime,
+    /// symbol이 없으면 전체 symbol
+    PremiumIndex {
+        symbol: Option<String>,
+    },
+    /// `limit`: 최대 1000 (기본값 100)
+    FundingRate {
+        symbol: Option<String>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    },
+    FundingInfo,
+    OpenInterest {
+        symbol: String,
+    },
+    /// `limit`: 최대 500 (기본값 30), 최근 1달 데이터만 제공된다.
+    OpenInterestHist {
+        symbol: String,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    },
+    LongShortRatio {
+        kind: LongShortRatioKind,
+        symbol: String,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    },
+    TakerBuySellVolume {
+        symbol: String,
+        period: StatisticsPeriod,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 impl From<CommonEndpoint> for String {
     fn from(value: CommonEndpoint) -> Self {
         match value {
-            CommonEndpoint::Klines { symbol, interval, limit } => {
-                                let limit_or_default = limit.unwrap_or(500);
+            CommonEndpoint::Klines { .. } => {
                                 // format!("/fapi/v1/klines?symbol={}&interval={}&limit={}", symbol, interval, limit_or_default)
-                                format!("/fapi/v1/klines")
+                                "/fapi/v1/klines".to_string()
                             },
-            CommonEndpoint::OrderBook => {
-                                "".to_string()
+            CommonEndpoint::OrderBook { .. } => {
+                                "/fapi/v1/depth".to_string()
                             },
-            CommonEndpoint::RecentTradesList => {
-                                "".to_string()
+            CommonEndpoint::RecentTradesList { .. } => {
+                                "/fapi/v1/trades".to_string()
                             },
-            CommonEndpoint::HistoricalTrades => {
-                                "".to_string()
+            CommonEndpoint::HistoricalTrades { .. } => {
+                                "/fapi/v1/historicalTrades".to_string()
+                            }
+            CommonEndpoint::AggTrades { .. } => {
+                                "/fapi/v1/aggTrades".to_string()
                             }
             CommonEndpoint::ExchnageInfo => {
                         "/fapi/v1/exchangeInfo".to_string()
                     }
-            CommonEndpoint::Ticker => {
+            CommonEndpoint::Ticker { .. } => {
                         "/fapi/v1/ticker/24hr".to_string()
             },
+            CommonEndpoint::ServerTime => {
+                        "/fapi/v1/time".to_string()
+            },
+            CommonEndpoint::PremiumIndex { .. } => {
+                        "/fapi/v1/premiumIndex".to_string()
+            },
+            CommonEndpoint::FundingRate { .. } => {
+                        "/fapi/v1/fundingRate".to_string()
+            },
+            CommonEndpoint::FundingInfo => {
+                        "/fapi/v1/fundingInfo".to_string()
+            },
+            CommonEndpoint::OpenInterest { .. } => {
+                        "/fapi/v1/openInterest".to_string()
+            },
+            CommonEndpoint::OpenInterestHist { .. } => {
+                        "/futures/data/openInterestHist".to_string()
+            },
+            CommonEndpoint::LongShortRatio { kind, .. } => {
+                        kind.path().to_string()
+            },
+            CommonEndpoint::TakerBuySellVolume { .. } => {
+                        "/futures/data/takerlongshortRatio".to_string()
+            },
         }
     }
 }
@@ -228,15 +583,61 @@ impl From<CommonEndpoint> for String {
 impl Endpoint for CommonEndpoint {
     fn query(&self) -> String {
         match self {
-            CommonEndpoint::Klines { symbol, interval, limit } => {
-                                let limit = limit.unwrap_or(500);
-                                query!(symbol, interval, limit)   
+            CommonEndpoint::Klines { symbol, interval, start_time, end_time, limit } => {
+                                query!(symbol, interval, start_time, end_time, limit)   
                             },
-            CommonEndpoint::OrderBook => todo!(),
-            CommonEndpoint::RecentTradesList => todo!(),
-            CommonEndpoint::HistoricalTrades => todo!(),
+            CommonEndpoint::OrderBook { symbol, limit } => query!(symbol, limit),
+            CommonEndpoint::RecentTradesList { symbol, limit } => query!(symbol, limit),
+            CommonEndpoint::HistoricalTrades { symbol, limit, from_id } => query!(symbol, limit, from_id),
+            CommonEndpoint::AggTrades { symbol, from_id, start_time, end_time, limit } => {
+                query!(symbol, from_id, start_time, end_time, limit)
+            },
             CommonEndpoint::ExchnageInfo => query!(),
-            CommonEndpoint::Ticker => query!(),
+            CommonEndpoint::Ticker { symbol } => query!(symbol),
+            CommonEndpoint::ServerTime => query!(),
+            CommonEndpoint::PremiumIndex { symbol } => query!(symbol),
+            CommonEndpoint::FundingRate { symbol, start_time, end_time, limit } => query!(symbol, start_time, end_time, limit),
+            CommonEndpoint::FundingInfo => query!(),
+            CommonEndpoint::OpenInterest { symbol } => query!(symbol),
+            CommonEndpoint::OpenInterestHist { symbol, period, start_time, end_time, limit }
+            | CommonEndpoint::LongShortRatio { symbol, period, start_time, end_time, limit, .. }
+            | CommonEndpoint::TakerBuySellVolume { symbol, period, start_time, end_time, limit } => {
+                query!(symbol, period, start_time, end_time, limit)
+            },
+        }
+    }
+
+    fn weight(&self) -> u32 {
+        match self {
+            CommonEndpoint::Klines { limit, .. } => {
+                match limit.unwrap_or(500) {
+                    ..100 => 1,
+                    100..500 => 2,
+                    500..=1000 => 5,
+                    _ => 10,
+                }
+            },
+            CommonEndpoint::OrderBook { limit, .. } => {
+                match limit.unwrap_or(500) {
+                    ..=50 => 2,
+                    51..=100 => 5,
+                    101..=500 => 10,
+                    _ => 20,
+                }
+            },
+            CommonEndpoint::RecentTradesList { .. } => 5,
+            CommonEndpoint::HistoricalTrades { .. } | CommonEndpoint::AggTrades { .. } => 20,
+            // symbol 없이 전체를 요청하는 경우
+            CommonEndpoint::Ticker { symbol: None } => 40,
+            CommonEndpoint::PremiumIndex { symbol: None } => 10,
+            _ => 1,
+        }
+    }
+
+    fn security_type(&self) -> SecurityType {
+        match self {
+            CommonEndpoint::HistoricalTrades { .. } => SecurityType::MarketData,
+            _ => SecurityType::None,
         }
     }
 }
@@ -250,8 +651,10 @@ impl Endpoint for CommonEndpoint {
 pub enum UserEndpoint {
     Balance, 
     AccountConfig, 
+    /// `orig_client_order_id`가 있으면 그 주문을 조회한다.
     QueryOrder {
         symbol: String,
+        orig_client_order_id: Option<String>,
     },
     AllOrders {
         symbol: String,
@@ -267,14 +670,25 @@ impl Endpoint for UserEndpoint {
             UserEndpoint::AccountConfig => {
                 String::new()
             },
-            UserEndpoint::QueryOrder{ symbol } => {
-                query!(symbol)
+            UserEndpoint::QueryOrder{ symbol, orig_client_order_id } => {
+                query!(symbol, orig_client_order_id)
             },
             UserEndpoint::AllOrders{ symbol } => {
                 query!(symbol)
             },
         }
     }
+
+    fn weight(&self) -> u32 {
+        match self {
+            UserEndpoint::Balance | UserEndpoint::AccountConfig | UserEndpoint::AllOrders { .. } => 5,
+            _ => 1,
+        }
+    }
+
+    fn security_type(&self) -> SecurityType {
+        SecurityType::UserData
+    }
 }
 
 impl From<UserEndpoint> for String {
@@ -284,9 +698,9 @@ impl From<UserEndpoint> for String {
                 "fapi/v3/balance".to_string()
             },
             UserEndpoint::AccountConfig => {
-                String::new()
+                "fapi/v1/accountConfig".to_string()
             },
-            UserEndpoint::QueryOrder{ symbol: _ } => {
+            UserEndpoint::QueryOrder{ .. } => {
                 "fapi/v1/order".to_string()
             },
             UserEndpoint::AllOrders{ symbol: _ } => {
@@ -308,15 +722,19 @@ pub enum TradeEndpoint {
     AllOpenOrder {
         symbol: String,
     }, 
+    /// `None`인 파라미터는 보내지 않는다.
+    /// 주문 종류(`type`)별 필수 파라미터는 바이낸스 문서를 참고한다.
     NewOrder {
         symbol: String,
         side: String,   // buy or sell
-        r#type: String, // order type ... additional parameter need... 
-        time_in_force: String,
-        quantity: String,
-        price: String,
-        stop_price: f64,
-        callback_rate: f64,
+        r#type: String, // LIMIT, MARKET, STOP, TAKE_PROFIT, STOP_MARKET, TAKE_PROFIT_MARKET, TRAILING_STOP_MARKET
+        time_in_force: Option<String>,
+        quantity: Option<String>,
+        price: Option<String>,
+        stop_price: Option<String>,
+        callback_rate: Option<f64>,
+        /// 결과를 모르는 주문을 재시도하기 전에 조회할 때 사용하는 id
+        new_client_order_id: Option<String>,
     }
 }
 
@@ -339,30 +757,34 @@ impl Endpoint for TradeEndpoint {
             TradeEndpoint::AllOpenOrder { symbol } => {
                         query!(symbol)
                     },
-            TradeEndpoint::NewOrder { symbol, side, r#type, time_in_force, quantity, price,  stop_price, callback_rate} => {
-                match r#type.as_str() {
-                    "LIMIT" => {
-                        query!(symbol, side, r#type, time_in_force, quantity, price)
-                    },
-                    "MARKET" => {
-                        query!(symbol, side, r#type, quantity)
-                    },
-                    "STOP" | "TAKE_PROFIT" => {
-                        query!(symbol, side, r#type, quantity, price, stop_price)
-                    }, 
-                    "STOP_MARKET" | "TAKE_PROFIT_MARKET" => {
-                        query!(symbol, side, r#type, stop_price)
-                    },
-                    "TRAILING_STOP_MARKET" => {
-                        query!(symbol, side, r#type, callback_rate)
-                    },
-                    _ => {
-                        "".to_string()
-                    }
-                }
+            TradeEndpoint::NewOrder { symbol, side, r#type, time_in_force, quantity, price, stop_price, callback_rate, new_client_order_id } => {
+                query!(symbol, side, r#type, time_in_force, quantity, price, stop_price, callback_rate, new_client_order_id)
             },
         }
     }
+
+    fn weight(&self) -> u32 {
+        match self {
+            // 주문은 IP weight 대신 ORDERS 제한을 사용한다.
+            TradeEndpoint::NewOrder { .. } => 0,
+            _ => 1,
+        }
+    }
+
+    fn is_order(&self) -> bool {
+        matches!(self, TradeEndpoint::NewOrder { .. })
+    }
+
+    fn method(&self) -> HttpMethod {
+        match self {
+            TradeEndpoint::CancelOrder { .. } | TradeEndpoint::AllOpenOrder { .. } => HttpMethod::Delete,
+            _ => HttpMethod::Post,
+        }
+    }
+
+    fn security_type(&self) -> SecurityType {
+        SecurityType::Trade
+    }
 }
 
 impl From<TradeEndpoint> for String {
@@ -370,16 +792,16 @@ impl From<TradeEndpoint> for String {
         match value {
             TradeEndpoint::Order => "/fapi/v1/order".to_string(),
             TradeEndpoint::Leverage { symbol: _, leverage: _ } => {
-                                format!("fapi/v1/leverage")
+                                "fapi/v1/leverage".to_string()
                             },
             TradeEndpoint::CancelOrder { symbol: _ } => {
-                                format!("fapi/v1/order")
+                                "fapi/v1/order".to_string()
                             },
             TradeEndpoint::AllOpenOrder { symbol: _ } => {
-                                format!("fapi/v1/allOpenOrders")
+                                "fapi/v1/allOpenOrders".to_string()
                             }
-            TradeEndpoint::NewOrder { symbol, side, r#type, time_in_force, quantity, price, stop_price, callback_rate } => {
-                                format!("fapi/v1/order")
+            TradeEndpoint::NewOrder { .. } => {
+                                "fapi/v1/order".to_string()
                             },
         }
     }
@@ -389,16 +811,85 @@ impl From<TradeEndpoint> for String {
 // 
 pub trait Endpoint {
     fn query(&self) -> String;
-    fn query_with_tiemstamp(&self) -> String {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH).unwrap()
-            .as_millis()
-            .to_string();
 
-        format!("{}&timestamp={}", self.query(), timestamp)
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Get
+    }
+
+    /// 서명 여부와 api key header 필요 여부를 결정한다.
+    fn security_type(&self) -> SecurityType {
+        SecurityType::None
+    }
+
+    /// 요청 weight (REQUEST_WEIGHT 제한에 사용)
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    /// ORDERS 제한에 포함되는 요청인지
+    fn is_order(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl HttpMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+        }
+    }
+}
+
+/// 바이낸스 endpoint security type
+/// - `None`: key 필요 없음
+/// - `MarketData`: api key header만 필요
+/// - `UserData`, `Trade`: api key header + signature 필요
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityType {
+    None,
+    MarketData,
+    UserData,
+    Trade,
+}
+
+impl SecurityType {
+    pub fn requires_api_key(&self) -> bool {
+        !matches!(self, SecurityType::None)
     }
+
+    pub fn requires_signature(&self) -> bool {
+        matches!(self, SecurityType::UserData | SecurityType::Trade)
+    }
+}
+
+/// query 뒤에 `recvWindow`, `timestamp`를 붙인다.
+pub fn signed_query(query: &str, timestamp: u64, recv_window: Option<u64>) -> String {
+    let mut signed = query.to_string();
+    if let Some(recv_window) = recv_window {
+        if !signed.is_empty() {
+            signed.push('&');
+        }
+        signed.push_str(&format!("recvWindow={}", recv_window));
+    }
+    if !signed.is_empty() {
+        signed.push('&');
+    }
+    signed.push_str(&format!("timestamp={}", timestamp));
+    signed
 }
 
+#[allow(dead_code)]
 struct Order {
     symbol: Option<String>,
     side: Option<String>,   // buy or sell
@@ -410,6 +901,7 @@ struct Order {
     callback_rate: Option<f64>,
 }
 
+#[allow(dead_code, clippy::too_many_arguments)]
 impl Order {
     pub fn new(
         symbol: Option<String>,
@@ -434,26 +926,947 @@ impl Order {
     }
 }
 
-// #[derive(Debug, Deserialize)]
-// pub struct BinanceSymbols {
-    
-// }
+/// fapi/v1/exchangeInfo
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfo {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    #[serde(default)]
+    pub assets: Vec<AssetInfo>,
+    pub symbols: Vec<BinanceSymbol>,
+}
+
+impl ExchangeInfo {
+    pub fn symbol(&self, symbol: &str) -> Option<&BinanceSymbol> {
+        self.symbols.iter().find(|s| s.symbol == symbol)
+    }
+
+    /// `query` 조건에 맞는 symbol
+    /// # Example
+    /// ```no_run
+    /// use crypto_trading::model::binance_model::{ContractType, ExchangeInfo, SymbolQuery, SymbolStatus};
+    ///
+    /// # fn run(info: ExchangeInfo) {
+    /// let query = SymbolQuery::new()
+    ///     .quote_asset("USDT")
+    ///     .status(SymbolStatus::Trading)
+    ///     .contract_type(ContractType::Perpetual);
+    /// let symbols: Vec<&str> = info.query(&query).map(|s| s.symbol.as_str()).collect();
+    /// # }
+    /// ```
+    pub fn query<'a>(&'a self, query: &'a SymbolQuery) -> impl Iterator<Item = &'a BinanceSymbol> + 'a {
+        self.symbols.iter().filter(move |s| query.matches(s))
+    }
+}
 
-/// exchangeInfo 
-#[derive(Debug, Deserialize)]
+/// exchangeInfo의 `assets`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo {
+    pub asset: String,
+    /// 멀티에셋 모드에서 증거금으로 사용할 수 있는지
+    pub margin_available: bool,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub auto_asset_exchange: Option<f64>,
+}
+
+/// exchangeInfo의 `symbols`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct BinanceSymbol {
     pub symbol: String,
+    #[serde(default)]
+    pub pair: String,
+    pub contract_type: ContractType,
+    #[serde(default)]
+    pub delivery_date: u64,
+    #[serde(default)]
+    pub onboard_date: u64,
+    pub status: SymbolStatus,
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub margin_asset: String,
+    /// 가격 표시 자릿수 (주문 가격은 `tickSize`를 따라야 한다.)
+    pub price_precision: u32,
+    /// 수량 표시 자릿수 (주문 수량은 `stepSize`를 따라야 한다.)
+    pub quantity_precision: u32,
+    pub base_asset_precision: u32,
+    pub quote_precision: u32,
+    #[serde(default)]
+    pub underlying_type: String,
+    #[serde(default)]
+    pub underlying_sub_type: Vec<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub trigger_protect: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub liquidation_fee: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub market_take_bound: Option<f64>,
+    #[serde(default)]
+    pub order_types: Vec<String>,
+    #[serde(default)]
+    pub time_in_force: Vec<String>,
+    #[serde(default)]
+    pub filters: Vec<SymbolFilter>,
+}
+
+impl BinanceSymbol {
+    pub fn is_trading(&self) -> bool {
+        self.status == SymbolStatus::Trading
+    }
+
+    pub fn price_filter(&self) -> Option<&PriceFilter> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::PriceFilter(f) => Some(f),
+            _ => None,
+        })
+    }
+
+    pub fn lot_size(&self) -> Option<&LotSize> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::LotSize(f) => Some(f),
+            _ => None,
+        })
+    }
+
+    /// 시장가 주문에 적용되는 수량 제한
+    pub fn market_lot_size(&self) -> Option<&LotSize> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MarketLotSize(f) => Some(f),
+            _ => None,
+        })
+    }
+
+    pub fn min_notional(&self) -> Option<&MinNotional> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MinNotional(f) => Some(f),
+            _ => None,
+        })
+    }
+
+    pub fn percent_price(&self) -> Option<&PercentPrice> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::PercentPrice(f) => Some(f),
+            _ => None,
+        })
+    }
+
+    /// 미체결 주문 최대 개수
+    pub fn max_num_orders(&self) -> Option<u32> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MaxNumOrders { limit } => Some(*limit),
+            _ => None,
+        })
+    }
+
+    /// 미체결 algo(조건부) 주문 최대 개수
+    pub fn max_num_algo_orders(&self) -> Option<u32> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MaxNumAlgoOrders { limit } => Some(*limit),
+            _ => None,
+        })
+    }
+
+    pub fn supports_order_type(&self, order_type: &str) -> bool {
+        self.order_types.iter().any(|t| t == order_type)
+    }
 }
 
-/// Ticker
-#[derive(Debug, Deserialize)]
+/// 모르는 값은 `Unknown`이 된다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractType {
+    Perpetual,
+    CurrentMonth,
+    NextMonth,
+    CurrentQuarter,
+    NextQuarter,
+    PerpetualDelivering,
+    #[serde(other)]
+    Unknown,
+}
+
+/// 모르는 값은 `Unknown`이 된다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SymbolStatus {
+    PendingTrading,
+    Trading,
+    PreDelivering,
+    Delivering,
+    Delivered,
+    PreSettle,
+    Settling,
+    Close,
+    #[serde(other)]
+    Unknown,
+}
+
+/// exchangeInfo의 symbol `filters`
+/// 모르는 filter는 `Unknown`이 된다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "filterType")]
+pub enum SymbolFilter {
+    #[serde(rename = "PRICE_FILTER")]
+    PriceFilter(PriceFilter),
+    #[serde(rename = "LOT_SIZE")]
+    LotSize(LotSize),
+    #[serde(rename = "MARKET_LOT_SIZE")]
+    MarketLotSize(LotSize),
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional(MinNotional),
+    #[serde(rename = "PERCENT_PRICE")]
+    PercentPrice(PercentPrice),
+    #[serde(rename = "MAX_NUM_ORDERS")]
+    MaxNumOrders { limit: u32 },
+    #[serde(rename = "MAX_NUM_ALGO_ORDERS")]
+    MaxNumAlgoOrders { limit: u32 },
+    #[serde(other)]
+    Unknown,
+}
+
+/// `PRICE_FILTER`
+/// 가격은 `min_price` 이상, `max_price` 이하, `tick_size`의 배수여야 한다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFilter {
+    #[serde(deserialize_with = "de_f64")]
+    pub min_price: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub max_price: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub tick_size: f64,
+}
+
+/// `LOT_SIZE`, `MARKET_LOT_SIZE`
+/// 수량은 `min_qty` 이상, `max_qty` 이하, `step_size`의 배수여야 한다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotSize {
+    #[serde(deserialize_with = "de_f64")]
+    pub min_qty: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub max_qty: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub step_size: f64,
+}
+
+/// `MIN_NOTIONAL`
+/// 가격 * 수량이 `notional` 이상이어야 한다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MinNotional {
+    #[serde(deserialize_with = "de_f64")]
+    pub notional: f64,
+}
+
+/// `PERCENT_PRICE`
+/// 가격은 mark price * `multiplier_down` 이상, mark price * `multiplier_up` 이하여야 한다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PercentPrice {
+    #[serde(deserialize_with = "de_f64")]
+    pub multiplier_up: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub multiplier_down: f64,
+    #[serde(deserialize_with = "de_u64")]
+    pub multiplier_decimal: u64,
+}
+
+/// `ExchangeInfo::query` 조건
+/// 지정하지 않은 조건은 검사하지 않는다.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolQuery {
+    quote_asset: Option<String>,
+    base_asset: Option<String>,
+    status: Option<SymbolStatus>,
+    contract_type: Option<ContractType>,
+}
+
+impl SymbolQuery {
+    pub fn new() -> Self {
+        SymbolQuery::default()
+    }
+
+    pub fn quote_asset(mut self, asset: impl Into<String>) -> Self {
+        self.quote_asset = Some(asset.into());
+        self
+    }
+
+    pub fn base_asset(mut self, asset: impl Into<String>) -> Self {
+        self.base_asset = Some(asset.into());
+        self
+    }
+
+    pub fn status(mut self, status: SymbolStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn contract_type(mut self, contract_type: ContractType) -> Self {
+        self.contract_type = Some(contract_type);
+        self
+    }
+
+    pub fn matches(&self, symbol: &BinanceSymbol) -> bool {
+        self.quote_asset.as_ref().is_none_or(|a| *a == symbol.quote_asset)
+            && self.base_asset.as_ref().is_none_or(|a| *a == symbol.base_asset)
+            && self.status.is_none_or(|s| s == symbol.status)
+            && self.contract_type.is_none_or(|c| c == symbol.contract_type)
+    }
+}
+
+/// exchangeInfo의 `rateLimits`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    pub rate_limit_type: String,
+    pub interval: String,
+    pub interval_num: u64,
+    pub limit: u32,
+}
+
+/// fapi/v1/ticker/24hr 전체 symbol
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
 pub struct Tickers {
-    tickers: Vec<Ticker>
+    pub tickers: Vec<Ticker>
+}
+
+impl Tickers {
+    pub fn get(&self, symbol: &str) -> Option<&Ticker> {
+        self.tickers.iter().find(|t| t.symbol == symbol)
+    }
+
+    /// quote volume(USDT 거래대금)이 큰 순서로 정렬한다.
+    pub fn sort_by_quote_volume(&mut self) {
+        self.tickers.sort_by(|a, b| cmp_desc(a.quote_volume, b.quote_volume));
+    }
+
+    /// quote volume 상위 `n`개
+    pub fn top_by_quote_volume(&self, n: usize) -> Vec<&Ticker> {
+        self.top_by(n, |t| t.quote_volume)
+    }
+
+    /// 24시간 상승률 상위 `n`개
+    pub fn top_gainers(&self, n: usize) -> Vec<&Ticker> {
+        self.top_by(n, |t| t.price_change_percent)
+    }
+
+    /// 24시간 하락률 상위 `n`개
+    pub fn top_losers(&self, n: usize) -> Vec<&Ticker> {
+        self.top_by(n, |t| -t.price_change_percent)
+    }
+
+    /// `key`가 큰 순서로 `n`개, NaN은 가장 뒤로 보낸다.
+    fn top_by(&self, n: usize, key: impl Fn(&Ticker) -> f64) -> Vec<&Ticker> {
+        let mut ranked: Vec<&Ticker> = self.tickers.iter().collect();
+        ranked.sort_by(|a, b| cmp_desc(key(a), key(b)));
+        ranked.truncate(n);
+        ranked
+    }
 }
 
+/// 내림차순 비교, NaN은 가장 작은 값으로 본다.
+fn cmp_desc(a: f64, b: f64) -> std::cmp::Ordering {
+    let key = |v: f64| if v.is_nan() { f64::NEG_INFINITY } else { v };
+    key(b).total_cmp(&key(a))
+}
+
+/// fapi/v1/ticker/24hr
+/// 비어있거나 숫자가 아닌 값은 NaN이 된다. (한 symbol 때문에 전체 목록이 실패하지 않도록)
 #[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Ticker {
     pub symbol: String,
-    #[serde(rename = "quoteVolume")]
-    pub quote_volume: String,
-}
\ No newline at end of file
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub price_change: f64,
+    /// `1.5`면 1.5%
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub price_change_percent: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub weighted_avg_price: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub last_price: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub last_qty: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub open_price: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub high_price: f64,
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub low_price: f64,
+    /// base asset 거래량
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub volume: f64,
+    /// quote asset(USDT) 거래대금
+    #[serde(deserialize_with = "de_f64_or_nan")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    /// 거래 횟수
+    pub count: u64,
+}
+
+/// fapi/v1/premiumIndex
+/// mark price와 다음 funding 정보
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub mark_price: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub index_price: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub estimated_settle_price: f64,
+    /// 만기가 있는 delivery 계약(`BTCUSDT_250926` 등)은 비어있다.
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub last_funding_rate: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub interest_rate: Option<f64>,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+/// fapi/v1/fundingRate
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub funding_rate: f64,
+    pub funding_time: u64,
+    /// 오래된 기록은 비어있다.
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub mark_price: Option<f64>,
+}
+
+/// fapi/v1/fundingInfo
+/// funding rate 상한, 하한이나 주기가 조정된 symbol만 포함된다.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub adjusted_funding_rate_cap: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub adjusted_funding_rate_floor: f64,
+    pub funding_interval_hours: u32,
+}
+/// openInterestHist, long/short ratio, taker buy/sell volume의 집계 주기
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum StatisticsPeriod {
+    Min5,
+    Min15,
+    Min30,
+    Hour1,
+    Hour2,
+    Hour4,
+    Hour6,
+    Hour12,
+    Day1,
+}
+
+impl StatisticsPeriod {
+    pub const ALL: [StatisticsPeriod; 9] = [
+        StatisticsPeriod::Min5, StatisticsPeriod::Min15, StatisticsPeriod::Min30,
+        StatisticsPeriod::Hour1, StatisticsPeriod::Hour2, StatisticsPeriod::Hour4, StatisticsPeriod::Hour6, StatisticsPeriod::Hour12,
+        StatisticsPeriod::Day1,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatisticsPeriod::Min5 => "5m",
+            StatisticsPeriod::Min15 => "15m",
+            StatisticsPeriod::Min30 => "30m",
+            StatisticsPeriod::Hour1 => "1h",
+            StatisticsPeriod::Hour2 => "2h",
+            StatisticsPeriod::Hour4 => "4h",
+            StatisticsPeriod::Hour6 => "6h",
+            StatisticsPeriod::Hour12 => "12h",
+            StatisticsPeriod::Day1 => "1d",
+        }
+    }
+
+    /// 같은 길이의 kline interval (kline과 맞춰볼 때 사용)
+    pub fn kline_interval(&self) -> KlineInterval {
+        match self {
+            StatisticsPeriod::Min5 => KlineInterval::Min5,
+            StatisticsPeriod::Min15 => KlineInterval::Min15,
+            StatisticsPeriod::Min30 => KlineInterval::Min30,
+            StatisticsPeriod::Hour1 => KlineInterval::Hour1,
+            StatisticsPeriod::Hour2 => KlineInterval::Hour2,
+            StatisticsPeriod::Hour4 => KlineInterval::Hour4,
+            StatisticsPeriod::Hour6 => KlineInterval::Hour6,
+            StatisticsPeriod::Hour12 => KlineInterval::Hour12,
+            StatisticsPeriod::Day1 => KlineInterval::Day1,
+        }
+    }
+}
+
+impl fmt::Display for StatisticsPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for StatisticsPeriod {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatisticsPeriod::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s)
+            .ok_or_else(|| crate::Error::Config(format!("unknown statistics period: {}", s)))
+    }
+}
+
+impl TryFrom<String> for StatisticsPeriod {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<StatisticsPeriod> for String {
+    fn from(value: StatisticsPeriod) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl QueryValue for StatisticsPeriod {
+    fn query_value(&self) -> Option<String> {
+        Some(self.as_str().to_string())
+    }
+}
+
+/// long/short ratio 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LongShortRatioKind {
+    /// 전체 계정 수 기준
+    GlobalAccount,
+    /// 상위 trader 계정 수 기준
+    TopTraderAccount,
+    /// 상위 trader 포지션 크기 기준
+    TopTraderPosition,
+}
+
+impl LongShortRatioKind {
+    pub fn path(&self) -> &'static str {
+        match self {
+            LongShortRatioKind::GlobalAccount => "/futures/data/globalLongShortAccountRatio",
+            LongShortRatioKind::TopTraderAccount => "/futures/data/topLongShortAccountRatio",
+            LongShortRatioKind::TopTraderPosition => "/futures/data/topLongShortPositionRatio",
+        }
+    }
+}
+
+/// fapi/v1/openInterest
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub open_interest: f64,
+    pub time: u64,
+}
+
+/// futures/data/openInterestHist
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHist {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub sum_open_interest: f64,
+    #[serde(deserialize_with = "de_f64")]
+    pub sum_open_interest_value: f64,
+    #[serde(deserialize_with = "de_u64")]
+    pub timestamp: u64,
+}
+
+/// futures/data/globalLongShortAccountRatio, topLongShortAccountRatio, topLongShortPositionRatio
+/// `long`, `short`는 비율(0.0 ~ 1.0)이다.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_f64")]
+    pub long_short_ratio: f64,
+    #[serde(rename = "longAccount", alias = "longPosition", deserialize_with = "de_f64")]
+    pub long: f64,
+    #[serde(rename = "shortAccount", alias = "shortPosition", deserialize_with = "de_f64")]
+    pub short: f64,
+    #[serde(deserialize_with = "de_u64")]
+    pub timestamp: u64,
+}
+
+/// futures/data/takerlongshortRatio
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    #[serde(deserialize_with = "de_f64")]
+    pub buy_sell_ratio: f64,
+    #[serde(rename = "buyVol", deserialize_with = "de_f64")]
+    pub buy_volume: f64,
+    #[serde(rename = "sellVol", deserialize_with = "de_f64")]
+    pub sell_volume: f64,
+    #[serde(deserialize_with = "de_u64")]
+    pub timestamp: u64,
+}
+
+/// fapi/v1/depth
+/// `bids`는 가격 내림차순, `asks`는 가격 오름차순으로 정렬되어 있다.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawOrderBook")]
+pub struct OrderBook {
+    pub last_update_id: u64,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+/// 호가 한 단계
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceLevel {
+    pub price: f64,
+    pub quantity: f64,
+}
+
+#[derive(Deserialize)]
+struct RawOrderBook {
+    #[serde(rename = "lastUpdateId")]
+    last_update_id: u64,
+    bids: Vec<(String, String)>,
+    asks: Vec<(String, String)>,
+}
+
+impl TryFrom<RawOrderBook> for OrderBook {
+    type Error = String;
+
+    fn try_from(raw: RawOrderBook) -> Result<Self, Self::Error> {
+        let mut bids = parse_levels(raw.bids)?;
+        let mut asks = parse_levels(raw.asks)?;
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        Result::Ok(OrderBook { last_update_id: raw.last_update_id, bids, asks })
+    }
+}
+
+fn parse_levels(levels: Vec<(String, String)>) -> Result<Vec<PriceLevel>, String> {
+    levels
+        .into_iter()
+        .map(|(price, quantity)| {
+            let price = price.parse::<f64>().map_err(|_| format!("invalid price: {}", price))?;
+            let quantity = quantity.parse::<f64>().map_err(|_| format!("invalid quantity: {}", quantity))?;
+            Result::Ok(PriceLevel { price, quantity })
+        })
+        .collect()
+}
+
+impl OrderBook {
+    /// 가장 높은 매수 호가
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.first().copied()
+    }
+
+    /// 가장 낮은 매도 호가
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.first().copied()
+    }
+
+    pub fn mid_price(&self) -> Option<f64> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / 2.0)
+    }
+
+    /// best ask - best bid
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// `price`까지(포함) 쌓여있는 매수 수량
+    /// `price`에 매도 주문을 넣었을 때 체결될 수 있는 최대 수량이다.
+    pub fn bid_depth_to(&self, price: f64) -> f64 {
+        self.bids.iter().take_while(|l| l.price >= price).map(|l| l.quantity).sum()
+    }
+
+    /// `price`까지(포함) 쌓여있는 매도 수량
+    /// `price`에 매수 주문을 넣었을 때 체결될 수 있는 최대 수량이다.
+    pub fn ask_depth_to(&self, price: f64) -> f64 {
+        self.asks.iter().take_while(|l| l.price <= price).map(|l| l.quantity).sum()
+    }
+}
+
+/// fapi/v1/trades, fapi/v1/historicalTrades
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(deserialize_with = "de_f64")]
+    pub price: f64,
+    #[serde(rename = "qty", deserialize_with = "de_f64")]
+    pub quantity: f64,
+    #[serde(rename = "quoteQty", deserialize_with = "de_f64")]
+    pub quote_quantity: f64,
+    pub time: u64,
+    /// true면 매도 taker (매수 주문이 maker)
+    pub is_buyer_maker: bool,
+}
+
+/// fapi/v1/aggTrades
+/// 같은 가격, 같은 taker 주문으로 체결된 trade를 묶은 것
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+    #[serde(rename = "p", deserialize_with = "de_f64")]
+    pub price: f64,
+    #[serde(rename = "q", deserialize_with = "de_f64")]
+    pub quantity: f64,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub time: u64,
+    /// true면 매도 taker (매수 주문이 maker)
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// 숫자 또는 `"1583127900000"`처럼 문자열로 오는 정수
+fn de_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(u64),
+        String(String),
+    }
+
+    match Raw::deserialize(deserializer)? {
+        Raw::Number(value) => Result::Ok(value),
+        Raw::String(value) => value.parse::<u64>().map_err(serde::de::Error::custom),
+    }
+}
+
+/// 빈 문자열이면 `None`
+fn de_opt_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) if !value.is_empty() => value.parse::<f64>().map(Some).map_err(serde::de::Error::custom),
+        _ => Result::Ok(None),
+    }
+}
+
+/// `"4.00000100"`처럼 문자열로 오는 숫자
+fn de_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse::<f64>().map_err(serde::de::Error::custom)
+}
+
+/// 숫자가 아니면 NaN
+fn de_f64_or_nan<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    Result::Ok(value.parse::<f64>().unwrap_or(f64::NAN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// UTC 시각 (ms)
+    fn ts(year: i32, month: u32, day: u32, hour: u32, min: u32) -> u64 {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis() as u64
+    }
+
+    #[test]
+    fn fixed_interval_boundaries() {
+        let t = ts(2024, 1, 3, 13, 37);
+
+        assert_eq!(KlineInterval::Min15.align(t), ts(2024, 1, 3, 13, 30));
+        assert_eq!(KlineInterval::Hour4.align(t), ts(2024, 1, 3, 12, 0));
+        assert_eq!(KlineInterval::Hour4.next_open_time(t), ts(2024, 1, 3, 16, 0));
+        assert_eq!(KlineInterval::Day1.close_time(ts(2024, 1, 3, 0, 0)), ts(2024, 1, 4, 0, 0) - 1);
+    }
+
+    #[test]
+    fn week_starts_on_monday() {
+        // 2024-01-01은 월요일이다.
+        let monday = ts(2024, 1, 1, 0, 0);
+        let next_monday = ts(2024, 1, 8, 0, 0);
+
+        assert_eq!(KlineInterval::Week1.align(ts(2024, 1, 3, 13, 37)), monday);
+        assert_eq!(KlineInterval::Week1.align(monday), monday);
+        assert_eq!(KlineInterval::Week1.align(next_monday - 1), monday);
+        assert_eq!(KlineInterval::Week1.align(next_monday), next_monday);
+        assert_eq!(KlineInterval::Week1.next_open_time(ts(2024, 1, 7, 23, 59)), next_monday);
+        assert_eq!(KlineInterval::Week1.close_time(monday), next_monday - 1);
+        // 1970-01-01은 목요일이므로 첫 월요일(1970-01-05) 이전은 0이다.
+        assert_eq!(KlineInterval::Week1.align(ts(1970, 1, 4, 0, 0)), 0);
+    }
+
+    #[test]
+    fn month_starts_on_first_day() {
+        assert_eq!(KlineInterval::Month1.align(ts(2024, 2, 15, 9, 0)), ts(2024, 2, 1, 0, 0));
+        // 윤년 2월
+        assert_eq!(KlineInterval::Month1.next_open_time(ts(2024, 2, 29, 23, 59)), ts(2024, 3, 1, 0, 0));
+        assert_eq!(KlineInterval::Month1.close_time(ts(2024, 2, 1, 0, 0)), ts(2024, 3, 1, 0, 0) - 1);
+        assert_eq!(KlineInterval::Month1.close_time(ts(2023, 2, 1, 0, 0)), ts(2023, 3, 1, 0, 0) - 1);
+    }
+
+    #[test]
+    fn month_rolls_over_year() {
+        let december = ts(2023, 12, 1, 0, 0);
+        let january = ts(2024, 1, 1, 0, 0);
+
+        assert_eq!(KlineInterval::Month1.align(ts(2023, 12, 31, 23, 59)), december);
+        assert_eq!(KlineInterval::Month1.align(january - 1), december);
+        assert_eq!(KlineInterval::Month1.align(january), january);
+        assert_eq!(KlineInterval::Month1.next_open_time(december), january);
+        assert_eq!(KlineInterval::Month1.close_time(december), january - 1);
+    }
+
+    #[test]
+    fn premium_index_allows_empty_funding_rate() {
+        let json = r#"[
+            {"symbol":"BTCUSDT","markPrice":"60000.10","indexPrice":"60010.00","estimatedSettlePrice":"60005.00",
+             "lastFundingRate":"0.00010000","interestRate":"0.00010000","nextFundingTime":1727740800000,"time":1727712000000},
+            {"symbol":"BTCUSDT_250926","markPrice":"62000.00","indexPrice":"60010.00","estimatedSettlePrice":"60005.00",
+             "lastFundingRate":"","interestRate":"","nextFundingTime":0,"time":1727712000000}
+        ]"#;
+
+        let indexes: Vec<PremiumIndex> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0].last_funding_rate, Some(0.0001));
+        assert_eq!(indexes[0].interest_rate, Some(0.0001));
+        assert_eq!(indexes[1].symbol, "BTCUSDT_250926");
+        assert_eq!(indexes[1].mark_price, 62000.0);
+        assert_eq!(indexes[1].last_funding_rate, None);
+        assert_eq!(indexes[1].interest_rate, None);
+    }
+
+    #[test]
+    fn parses_minute_and_month_by_case() {
+        assert_eq!("1m".parse::<KlineInterval>().unwrap(), KlineInterval::Min1);
+        assert_eq!("1M".parse::<KlineInterval>().unwrap(), KlineInterval::Month1);
+        assert!("1H".parse::<KlineInterval>().is_err());
+        assert!("".parse::<KlineInterval>().is_err());
+
+        for interval in KlineInterval::ALL {
+            assert_eq!(interval.to_string().parse::<KlineInterval>().unwrap(), interval);
+        }
+        assert_eq!(serde_json::to_string(&KlineInterval::Month1).unwrap(), r#""1M""#);
+        assert_eq!(serde_json::from_str::<KlineInterval>(r#""1m""#).unwrap(), KlineInterval::Min1);
+    }
+
+    fn ticker(symbol: &str, quote_volume: &str, price_change_percent: &str) -> String {
+        format!(
+            r#"{{"symbol":"{}","priceChange":"1.0","priceChangePercent":"{}","weightedAvgPrice":"100.0","lastPrice":"101.0","lastQty":"1.0",
+            "openPrice":"100.0","highPrice":"102.0","lowPrice":"99.0","volume":"10.0","quoteVolume":"{}",
+            "openTime":1700000000000,"closeTime":1700086399999,"firstId":1,"lastId":100,"count":100}}"#,
+            symbol, price_change_percent, quote_volume,
+        )
+    }
+
+    fn symbols(tickers: &[&Ticker]) -> Vec<String> {
+        tickers.iter().map(|t| t.symbol.clone()).collect()
+    }
+
+    #[test]
+    fn sorts_malformed_tickers_last() {
+        let json = format!("[{}]", [
+            ticker("AUSDT", "100.0", "1.0"),
+            ticker("NANUSDT", "NaN", "NaN"),
+            ticker("EMPTYUSDT", "", ""),
+            ticker("BUSDT", "300.0", "-2.0"),
+            ticker("TEXTUSDT", "abc", "abc"),
+            ticker("CUSDT", "200.0", "3.0"),
+        ].join(","));
+
+        let mut tickers: Tickers = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(tickers.tickers.len(), 6);
+        assert!(tickers.get("EMPTYUSDT").unwrap().quote_volume.is_nan());
+        assert!(tickers.get("TEXTUSDT").unwrap().quote_volume.is_nan());
+
+        // NaN끼리는 원래 순서를 유지한다.
+        let malformed = ["NANUSDT", "EMPTYUSDT", "TEXTUSDT"];
+        assert_eq!(symbols(&tickers.top_by_quote_volume(10)), [&["BUSDT", "CUSDT", "AUSDT"][..], &malformed].concat());
+        assert_eq!(symbols(&tickers.top_gainers(10)), [&["CUSDT", "AUSDT", "BUSDT"][..], &malformed].concat());
+        assert_eq!(symbols(&tickers.top_losers(10)), [&["BUSDT", "AUSDT", "CUSDT"][..], &malformed].concat());
+        assert_eq!(symbols(&tickers.top_by_quote_volume(2)), ["BUSDT", "CUSDT"]);
+        assert!(Tickers::default().top_gainers(3).is_empty());
+
+        tickers.sort_by_quote_volume();
+        let sorted: Vec<&str> = tickers.tickers.iter().map(|t| t.symbol.as_str()).collect();
+        assert_eq!(sorted, [&["BUSDT", "CUSDT", "AUSDT"][..], &malformed].concat());
+    }
+
+    #[test]
+    fn parses_depth_levels_and_helpers() {
+        // bids, asks 순서를 섞어서 정렬도 확인한다.
+        let json = r#"{"lastUpdateId":1027024,"E":1589436922972,"T":1589436922959,
+            "bids":[["100.0","2.000"],["100.50","1.500"],["99.5","4.000"]],
+            "asks":[["101.5","3.000"],["101.00","0.500"],["102.0","1.000"]]}"#;
+
+        let book: OrderBook = serde_json::from_str(json).unwrap();
+
+        assert_eq!(book.last_update_id, 1027024);
+        assert_eq!(book.bids.iter().map(|l| l.price).collect::<Vec<_>>(), vec![100.5, 100.0, 99.5]);
+        assert_eq!(book.asks.iter().map(|l| l.price).collect::<Vec<_>>(), vec![101.0, 101.5, 102.0]);
+        assert_eq!(book.best_bid(), Some(PriceLevel { price: 100.5, quantity: 1.5 }));
+        assert_eq!(book.best_ask(), Some(PriceLevel { price: 101.0, quantity: 0.5 }));
+        assert_eq!(book.mid_price(), Some(100.75));
+        assert_eq!(book.spread(), Some(0.5));
+        assert_eq!(book.bid_depth_to(100.0), 3.5);
+        assert_eq!(book.bid_depth_to(99.0), 7.5);
+        assert_eq!(book.bid_depth_to(101.0), 0.0);
+        assert_eq!(book.ask_depth_to(101.5), 3.5);
+        assert_eq!(book.ask_depth_to(100.0), 0.0);
+    }
+
+    #[test]
+    fn handles_empty_order_book() {
+        let book: OrderBook = serde_json::from_str(r#"{"lastUpdateId":1,"bids":[],"asks":[["101.0","1.0"]]}"#).unwrap();
+
+        assert_eq!(book.best_bid(), None);
+        assert_eq!(book.best_ask(), Some(PriceLevel { price: 101.0, quantity: 1.0 }));
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.bid_depth_to(0.0), 0.0);
+
+        let book: OrderBook = serde_json::from_str(r#"{"lastUpdateId":1,"bids":[],"asks":[]}"#).unwrap();
+        assert_eq!(book.best_ask(), None);
+        assert_eq!(book.ask_depth_to(f64::MAX), 0.0);
+    }
+
+    #[test]
+    fn rejects_invalid_depth_level() {
+        let error = serde_json::from_str::<OrderBook>(r#"{"lastUpdateId":1,"bids":[["abc","1.0"]],"asks":[]}"#).unwrap_err();
+
+        assert!(error.to_string().contains("invalid price: abc"));
+    }
+}
@@ -1,27 +1,76 @@
-use std::future::Future;
-
-use crate::model::binance_model::{Klines, Ticker};
+use crate::model::binance_model::{
+    AggTrade, ExchangeInfo, FundingInfo, FundingRate, KlineInterval, Klines, LongShortRatio, LongShortRatioKind, OpenInterest, OpenInterestHist,
+    OrderBook, PremiumIndex, StatisticsPeriod, TakerBuySellVolume, Ticker, Tickers, Trade,
+};
 
+#[allow(async_fn_in_trait)]
 pub trait UserPort {
-   async fn get_account_balance(&self) -> anyhow::Result<String>;
-   async fn get_trade_fee(&self) -> anyhow::Result<String>;
-   async fn query_order(&self, symbol: &str) -> anyhow::Result<String>;
-   async fn all_orders(&self, symbol: &str) -> anyhow::Result<String>;
+   async fn get_account_balance(&self) -> crate::Result<String>;
+   async fn get_trade_fee(&self) -> crate::Result<String>;
+   async fn query_order(&self, symbol: &str) -> crate::Result<String>;
+   async fn all_orders(&self, symbol: &str) -> crate::Result<String>;
 }
 
+#[allow(async_fn_in_trait)]
 pub trait CommonPort {
-   // async fn get_kline(&self) -> anyhow::Result<Klines>;
-   async fn get_kline(&self, symbol: String, interval: &str, limit: Option<i32>) -> anyhow::Result<Klines>;
-   async fn get_symbol_list(&self) -> anyhow::Result<Vec<String>>;
-   async fn get_symbol_with_volume(&self) -> anyhow::Result<Vec<Ticker>>;
+   // async fn get_kline(&self) -> crate::Result<Klines>;
+   async fn get_kline(&self, symbol: String, interval: KlineInterval, limit: Option<i32>) -> crate::Result<Klines>;
+   async fn get_klines_range(&self, symbol: &str, interval: KlineInterval, start_time: u64, end_time: u64) -> crate::Result<Klines>;
+   async fn get_exchange_info(&self) -> crate::Result<ExchangeInfo>;
+   async fn get_symbol_list(&self) -> crate::Result<Vec<String>>;
+   async fn get_symbol_with_volume(&self) -> crate::Result<Vec<Ticker>>;
+   async fn get_ticker(&self, symbol: &str) -> crate::Result<Ticker>;
+   async fn get_tickers(&self) -> crate::Result<Tickers>;
+   async fn get_order_book(&self, symbol: &str, limit: Option<u32>) -> crate::Result<OrderBook>;
+   async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> crate::Result<Vec<Trade>>;
+   async fn get_historical_trades(&self, symbol: &str, limit: Option<u32>, from_id: Option<u64>) -> crate::Result<Vec<Trade>>;
+   async fn get_agg_trades(
+       &self,
+       symbol: &str,
+       from_id: Option<u64>,
+       start_time: Option<u64>,
+       end_time: Option<u64>,
+       limit: Option<u32>,
+   ) -> crate::Result<Vec<AggTrade>>;
+   async fn get_premium_index(&self, symbol: Option<&str>) -> crate::Result<Vec<PremiumIndex>>;
+   async fn get_funding_rate_history(&self, symbol: &str, start_time: u64, end_time: u64) -> crate::Result<Vec<FundingRate>>;
+   async fn get_funding_info(&self) -> crate::Result<Vec<FundingInfo>>;
+   async fn get_open_interest(&self, symbol: &str) -> crate::Result<OpenInterest>;
+   async fn get_open_interest_hist(
+       &self,
+       symbol: &str,
+       period: StatisticsPeriod,
+       start_time: Option<u64>,
+       end_time: Option<u64>,
+       limit: Option<u32>,
+   ) -> crate::Result<Vec<OpenInterestHist>>;
+   async fn get_long_short_ratio(
+       &self,
+       kind: LongShortRatioKind,
+       symbol: &str,
+       period: StatisticsPeriod,
+       start_time: Option<u64>,
+       end_time: Option<u64>,
+       limit: Option<u32>,
+   ) -> crate::Result<Vec<LongShortRatio>>;
+   async fn get_taker_buy_sell_volume(
+       &self,
+       symbol: &str,
+       period: StatisticsPeriod,
+       start_time: Option<u64>,
+       end_time: Option<u64>,
+       limit: Option<u32>,
+   ) -> crate::Result<Vec<TakerBuySellVolume>>;
 }
 
 // adapter는 raw date(json string)를 넘기도록 한다.
 
+#[allow(async_fn_in_trait)]
 pub trait TradePort {
-   async fn order_position(&self) -> anyhow::Result<()>;
-   async fn change_leverage(&self, symbol: &str, leverage: i32) -> anyhow::Result<String>;
-   async fn all_open_orders(&self, symbol: &str) -> anyhow::Result<String>;
+   async fn order_position(&self) -> crate::Result<()>;
+   async fn change_leverage(&self, symbol: &str, leverage: i32) -> crate::Result<String>;
+   async fn all_open_orders(&self, symbol: &str) -> crate::Result<String>;
+   #[allow(clippy::too_many_arguments)]
    async fn new_order(
        &self,
        symbol: Option<String>,
@@ -32,6 +81,6 @@ pub trait TradePort {
        price: Option<String>,
        stop_price: Option<f64>,
        callback_rate: Option<f64>,
-   ) -> anyhow::Result<String>;
+   ) -> crate::Result<String>;
 }
 